@@ -0,0 +1,502 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+use crate::astronomy::ops;
+use crate::astronomy::unit::{Angle, Coordinates};
+
+// The mean length of the synodic month in days.
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+// The periodic terms for the longitude and the distance of the moon:
+// the multiples of D, M, M' and F, followed by the coefficient of the
+// sine for the longitude (in 0.000001°) and of the cosine for the
+// distance (in 0.001 km).
+// Table from Astronomical Algorithms page 339
+#[rustfmt::skip]
+const LONGITUDE_AND_DISTANCE_TERMS: [[f64; 6]; 60] = [
+    [0.0, 0.0, 1.0, 0.0, 6288774.0, -20905355.0],
+    [2.0, 0.0, -1.0, 0.0, 1274027.0, -3699111.0],
+    [2.0, 0.0, 0.0, 0.0, 658314.0, -2955968.0],
+    [0.0, 0.0, 2.0, 0.0, 213618.0, -569925.0],
+    [0.0, 1.0, 0.0, 0.0, -185116.0, 48888.0],
+    [0.0, 0.0, 0.0, 2.0, -114332.0, -3149.0],
+    [2.0, 0.0, -2.0, 0.0, 58793.0, 246158.0],
+    [2.0, -1.0, -1.0, 0.0, 57066.0, -152138.0],
+    [2.0, 0.0, 1.0, 0.0, 53322.0, -170733.0],
+    [2.0, -1.0, 0.0, 0.0, 45758.0, -204586.0],
+    [0.0, 1.0, -1.0, 0.0, -40923.0, -129620.0],
+    [1.0, 0.0, 0.0, 0.0, -34720.0, 108743.0],
+    [0.0, 1.0, 1.0, 0.0, -30383.0, 104755.0],
+    [2.0, 0.0, 0.0, -2.0, 15327.0, 10321.0],
+    [0.0, 0.0, 1.0, 2.0, -12528.0, 0.0],
+    [0.0, 0.0, 1.0, -2.0, 10980.0, 79661.0],
+    [4.0, 0.0, -1.0, 0.0, 10675.0, -34782.0],
+    [0.0, 0.0, 3.0, 0.0, 10034.0, -23210.0],
+    [4.0, 0.0, -2.0, 0.0, 8548.0, -21636.0],
+    [2.0, 1.0, -1.0, 0.0, -7888.0, 24208.0],
+    [2.0, 1.0, 0.0, 0.0, -6766.0, 30824.0],
+    [1.0, 0.0, -1.0, 0.0, -5163.0, -8379.0],
+    [1.0, 1.0, 0.0, 0.0, 4987.0, -16675.0],
+    [2.0, -1.0, 1.0, 0.0, 4036.0, -12831.0],
+    [2.0, 0.0, 2.0, 0.0, 3994.0, -10445.0],
+    [4.0, 0.0, 0.0, 0.0, 3861.0, -11650.0],
+    [2.0, 0.0, -3.0, 0.0, 3665.0, 14403.0],
+    [0.0, 1.0, -2.0, 0.0, -2689.0, -7003.0],
+    [2.0, 0.0, -1.0, 2.0, -2602.0, 0.0],
+    [2.0, -1.0, -2.0, 0.0, 2390.0, 10056.0],
+    [1.0, 0.0, 1.0, 0.0, -2348.0, 6322.0],
+    [2.0, -2.0, 0.0, 0.0, 2236.0, -9884.0],
+    [0.0, 1.0, 2.0, 0.0, -2120.0, 5751.0],
+    [0.0, 2.0, 0.0, 0.0, -2069.0, 0.0],
+    [2.0, -2.0, -1.0, 0.0, 2048.0, -4950.0],
+    [2.0, 0.0, 1.0, -2.0, -1773.0, 4130.0],
+    [2.0, 0.0, 0.0, 2.0, -1595.0, 0.0],
+    [4.0, -1.0, -1.0, 0.0, 1215.0, -3958.0],
+    [0.0, 0.0, 2.0, 2.0, -1110.0, 0.0],
+    [3.0, 0.0, -1.0, 0.0, -892.0, 3258.0],
+    [2.0, 1.0, 1.0, 0.0, -810.0, 2616.0],
+    [4.0, -1.0, -2.0, 0.0, 759.0, -1897.0],
+    [0.0, 2.0, -1.0, 0.0, -713.0, -2117.0],
+    [2.0, 2.0, -1.0, 0.0, -700.0, 2354.0],
+    [2.0, 1.0, -2.0, 0.0, 691.0, 0.0],
+    [2.0, -1.0, 0.0, -2.0, 596.0, 0.0],
+    [4.0, 0.0, 1.0, 0.0, 549.0, -1423.0],
+    [0.0, 0.0, 4.0, 0.0, 537.0, -1117.0],
+    [4.0, -1.0, 0.0, 0.0, 520.0, -1571.0],
+    [1.0, 0.0, -2.0, 0.0, -487.0, -1739.0],
+    [2.0, 1.0, 0.0, -2.0, -399.0, 0.0],
+    [0.0, 0.0, 2.0, -2.0, -381.0, -4421.0],
+    [1.0, 1.0, 1.0, 0.0, 351.0, 0.0],
+    [3.0, 0.0, -2.0, 0.0, -340.0, 0.0],
+    [4.0, 0.0, -3.0, 0.0, 330.0, 0.0],
+    [2.0, -1.0, 2.0, 0.0, 327.0, 0.0],
+    [0.0, 2.0, 1.0, 0.0, -323.0, 1165.0],
+    [1.0, 1.0, -1.0, 0.0, 299.0, 0.0],
+    [2.0, 0.0, 3.0, 0.0, 294.0, 0.0],
+    [2.0, 0.0, -1.0, -2.0, 0.0, 8752.0],
+];
+
+// The periodic terms for the latitude of the moon: the multiples
+// of D, M, M' and F, followed by the coefficient of the sine
+// (in 0.000001°).
+// Table from Astronomical Algorithms page 341
+#[rustfmt::skip]
+const LATITUDE_TERMS: [[f64; 5]; 60] = [
+    [0.0, 0.0, 0.0, 1.0, 5128122.0],
+    [0.0, 0.0, 1.0, 1.0, 280602.0],
+    [0.0, 0.0, 1.0, -1.0, 277693.0],
+    [2.0, 0.0, 0.0, -1.0, 173237.0],
+    [2.0, 0.0, -1.0, 1.0, 55413.0],
+    [2.0, 0.0, -1.0, -1.0, 46271.0],
+    [2.0, 0.0, 0.0, 1.0, 32573.0],
+    [0.0, 0.0, 2.0, 1.0, 17198.0],
+    [2.0, 0.0, 1.0, -1.0, 9266.0],
+    [0.0, 0.0, 2.0, -1.0, 8822.0],
+    [2.0, -1.0, 0.0, -1.0, 8216.0],
+    [2.0, 0.0, -2.0, -1.0, 4324.0],
+    [2.0, 0.0, 1.0, 1.0, 4200.0],
+    [2.0, 1.0, 0.0, -1.0, -3359.0],
+    [2.0, -1.0, -1.0, 1.0, 2463.0],
+    [2.0, -1.0, 0.0, 1.0, 2211.0],
+    [2.0, -1.0, -1.0, -1.0, 2065.0],
+    [0.0, 1.0, -1.0, -1.0, -1870.0],
+    [4.0, 0.0, -1.0, -1.0, 1828.0],
+    [0.0, 1.0, 0.0, 1.0, -1794.0],
+    [0.0, 0.0, 0.0, 3.0, -1749.0],
+    [0.0, 1.0, -1.0, 1.0, -1565.0],
+    [1.0, 0.0, 0.0, 1.0, -1491.0],
+    [0.0, 1.0, 1.0, 1.0, -1475.0],
+    [0.0, 1.0, 1.0, -1.0, -1410.0],
+    [0.0, 1.0, 0.0, -1.0, -1344.0],
+    [1.0, 0.0, 0.0, -1.0, -1335.0],
+    [0.0, 0.0, 3.0, 1.0, 1107.0],
+    [4.0, 0.0, 0.0, -1.0, 1021.0],
+    [4.0, 0.0, -1.0, 1.0, 833.0],
+    [0.0, 0.0, 1.0, -3.0, 777.0],
+    [4.0, 0.0, -2.0, 1.0, 671.0],
+    [2.0, 0.0, 0.0, -3.0, 607.0],
+    [2.0, 0.0, 2.0, -1.0, 596.0],
+    [2.0, -1.0, 1.0, -1.0, 491.0],
+    [2.0, 0.0, -2.0, 1.0, -451.0],
+    [0.0, 0.0, 3.0, -1.0, 439.0],
+    [2.0, 0.0, 2.0, 1.0, 422.0],
+    [2.0, 0.0, -3.0, -1.0, 421.0],
+    [2.0, 1.0, -1.0, 1.0, -366.0],
+    [2.0, 1.0, 0.0, 1.0, -351.0],
+    [4.0, 0.0, 0.0, 1.0, 331.0],
+    [2.0, -1.0, 1.0, 1.0, 315.0],
+    [2.0, -2.0, 0.0, -1.0, 302.0],
+    [0.0, 0.0, 1.0, 3.0, -283.0],
+    [2.0, 1.0, 1.0, -1.0, -229.0],
+    [1.0, 1.0, 0.0, -1.0, 223.0],
+    [1.0, 1.0, 0.0, 1.0, 223.0],
+    [0.0, 1.0, -2.0, -1.0, -220.0],
+    [2.0, 1.0, -1.0, -1.0, -220.0],
+    [1.0, 0.0, 1.0, 1.0, -185.0],
+    [2.0, -1.0, -2.0, -1.0, 181.0],
+    [0.0, 1.0, 2.0, 1.0, -177.0],
+    [4.0, 0.0, -2.0, -1.0, 176.0],
+    [4.0, -1.0, -1.0, -1.0, 166.0],
+    [1.0, 0.0, 1.0, -1.0, -164.0],
+    [4.0, 0.0, 1.0, -1.0, 132.0],
+    [1.0, 0.0, -1.0, -1.0, -119.0],
+    [4.0, -1.0, 0.0, -1.0, 115.0],
+    [2.0, -2.0, 0.0, 1.0, 107.0],
+];
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct LunarCoordinates {
+    // The apparent longitude of the moon, on the
+    // ecliptic and referred to the true equinox of the date.
+    pub longitude: Angle,
+
+    // The latitude of the moon, the angle between the
+    // moon and the ecliptic.
+    pub latitude: Angle,
+
+    // The distance between the centers of the Earth
+    // and the moon in kilometers.
+    pub distance: f64,
+
+    // The apparent right ascension of the moon.
+    pub right_ascension: Angle,
+
+    // The apparent declination of the moon.
+    pub declination: Angle,
+}
+
+impl LunarCoordinates {
+    /// The lunar coordinates at the given time.
+    pub fn for_time(time: DateTime<Utc>) -> Self {
+        LunarCoordinates::new(julian_ephemeris_day(time))
+    }
+
+    /// The lunar coordinates at the given Julian ephemeris day.
+    pub fn new(julian_day: f64) -> Self {
+        let julian_century = ops::julian_century(julian_day);
+        let t = julian_century;
+
+        // Equations from Astronomical Algorithms page 338
+        let mean_longitude = Angle::new(
+            218.3164477 + (481267.88123421 * t) - (0.0015786 * t.powf(2.0))
+                + (t.powf(3.0) / 538841.0)
+                - (t.powf(4.0) / 65194000.0),
+        )
+        .unwound();
+        let elongation = Angle::new(
+            297.8501921 + (445267.1114034 * t) - (0.0018819 * t.powf(2.0))
+                + (t.powf(3.0) / 545868.0)
+                - (t.powf(4.0) / 113065000.0),
+        )
+        .unwound();
+        let solar_anomaly = Angle::new(
+            357.5291092 + (35999.0502909 * t) - (0.0001536 * t.powf(2.0))
+                + (t.powf(3.0) / 24490000.0),
+        )
+        .unwound();
+        let lunar_anomaly = Angle::new(
+            134.9633964
+                + (477198.8675055 * t)
+                + (0.0087414 * t.powf(2.0))
+                + (t.powf(3.0) / 69699.0)
+                - (t.powf(4.0) / 14712000.0),
+        )
+        .unwound();
+        let argument_of_latitude = Angle::new(
+            93.2720950 + (483202.0175233 * t)
+                - (0.0036539 * t.powf(2.0))
+                - (t.powf(3.0) / 3526000.0)
+                + (t.powf(4.0) / 863310000.0),
+        )
+        .unwound();
+        let a1 = Angle::new(119.75 + (131.849 * t)).radians();
+        let a2 = Angle::new(53.09 + (479264.290 * t)).radians();
+        let a3 = Angle::new(313.45 + (481266.484 * t)).radians();
+
+        // The eccentricity of the Earth's orbit around the sun.
+        let eccentricity = 1.0 - (0.002516 * t) - (0.0000074 * t.powf(2.0));
+        let arguments = [
+            elongation.radians(),
+            solar_anomaly.radians(),
+            lunar_anomaly.radians(),
+            argument_of_latitude.radians(),
+        ];
+        let argument = |multiples: &[f64]| {
+            let angle = multiples
+                .iter()
+                .zip(arguments.iter())
+                .map(|(multiple, argument)| multiple * argument)
+                .sum::<f64>();
+            let correction = eccentricity.powf(multiples[1].abs());
+
+            (angle, correction)
+        };
+
+        let (mut longitude_terms, mut distance_terms) = (0.0, 0.0);
+        for term in LONGITUDE_AND_DISTANCE_TERMS.iter() {
+            let (angle, correction) = argument(&term[..4]);
+
+            longitude_terms += term[4] * correction * angle.sin();
+            distance_terms += term[5] * correction * angle.cos();
+        }
+
+        let mut latitude_terms = 0.0;
+        for term in LATITUDE_TERMS.iter() {
+            let (angle, correction) = argument(&term[..4]);
+
+            latitude_terms += term[4] * correction * angle.sin();
+        }
+
+        // Equations from Astronomical Algorithms page 342
+        let lp = mean_longitude.radians();
+        let f = argument_of_latitude.radians();
+        let mp = lunar_anomaly.radians();
+        longitude_terms += (3958.0 * a1.sin()) + (1962.0 * (lp - f).sin()) + (318.0 * a2.sin());
+        latitude_terms += (-2235.0 * lp.sin())
+            + (382.0 * a3.sin())
+            + (175.0 * (a1 - f).sin())
+            + (175.0 * (a1 + f).sin())
+            + (127.0 * (lp - mp).sin())
+            - (115.0 * (lp + mp).sin());
+
+        let mean_solar_longitude = ops::mean_solar_longitude(julian_century);
+        let ascending_lunar_node = ops::ascending_lunar_node_longitude(julian_century);
+        let nutation_longitude =
+            ops::nutation_in_longitude(mean_solar_longitude, mean_longitude, ascending_lunar_node);
+        let nutation_obliq =
+            ops::nutation_in_obliquity(mean_solar_longitude, mean_longitude, ascending_lunar_node);
+        let obliquity = Angle::new(
+            ops::mean_obliquity_of_the_ecliptic(julian_century).degrees + nutation_obliq,
+        )
+        .radians();
+
+        let longitude =
+            Angle::new(mean_longitude.degrees + (longitude_terms / 1000000.0) + nutation_longitude)
+                .unwound();
+        let latitude = Angle::new(latitude_terms / 1000000.0);
+        let distance = 385000.56 + (distance_terms / 1000.0);
+
+        // Equations from Astronomical Algorithms page 93
+        let right_ascension = Angle::from_radians(
+            ((longitude.radians().sin() * obliquity.cos())
+                - (latitude.radians().tan() * obliquity.sin()))
+            .atan2(longitude.radians().cos()),
+        )
+        .unwound();
+        let declination = Angle::from_radians(
+            ((latitude.radians().sin() * obliquity.cos())
+                + (latitude.radians().cos() * obliquity.sin() * longitude.radians().sin()))
+            .asin(),
+        );
+
+        LunarCoordinates {
+            longitude,
+            latitude,
+            distance,
+            right_ascension,
+            declination,
+        }
+    }
+
+    /// The equatorial horizontal parallax of the moon.
+    pub fn parallax(&self) -> Angle {
+        // Equation from Astronomical Algorithms page 337
+        Angle::from_radians((6378.14 / self.distance).asin())
+    }
+
+    /// The geocentric semi-diameter of the moon.
+    pub fn semi_diameter(&self) -> Angle {
+        // Equation from Astronomical Algorithms page 390
+        Angle::new(358473400.0 / self.distance / 3600.0)
+    }
+
+    /// The right ascension and the declination of the moon as seen
+    /// by the observer, at the given (geocentric) local hour angle.
+    pub fn topocentric(&self, coordinates: Coordinates, local_hour_angle: Angle) -> (Angle, Angle) {
+        // Equations from Astronomical Algorithms page 82 and 279,
+        // for an observer at sea level.
+        let latitude = coordinates.latitude_angle().radians();
+        let u = (0.99664719 * latitude.tan()).atan();
+        let rho_sine = 0.99664719 * u.sin();
+        let rho_cosine = u.cos();
+
+        let parallax = self.parallax().radians().sin();
+        let hour_angle = local_hour_angle.radians();
+        let declination = self.declination.radians();
+        let term1 = declination.cos() - (rho_cosine * parallax * hour_angle.cos());
+        let delta = (-rho_cosine * parallax * hour_angle.sin()).atan2(term1);
+        let topocentric_declination =
+            ((declination.sin() - (rho_sine * parallax)) * delta.cos()).atan2(term1);
+
+        (
+            Angle::new(self.right_ascension.degrees + Angle::from_radians(delta).degrees).unwound(),
+            Angle::from_radians(topocentric_declination),
+        )
+    }
+}
+
+// The Julian day of the time, including the fraction of the day.
+pub fn julian_day(time: DateTime<Utc>) -> f64 {
+    let hours = time.num_seconds_from_midnight() as f64 / 3600.0;
+
+    ops::julian_day(time.year(), time.month() as i32, time.day() as i32, hours)
+}
+
+// The Julian ephemeris day of the time, in dynamical time.
+pub fn julian_ephemeris_day(time: DateTime<Utc>) -> f64 {
+    let year = time.year() as f64 + (time.ordinal0() as f64 / 365.25);
+
+    julian_day(time) + (ops::delta_t(year) / 86400.0)
+}
+
+// The time of the Julian day.
+pub fn time_from_julian_day(julian_day: f64) -> Option<DateTime<Utc>> {
+    let seconds = ((julian_day - 2440587.5) * 86400.0).round() as i64;
+
+    DateTime::from_timestamp(seconds, 0)
+}
+
+// Apparent sidereal time at the given Julian day, the
+// hour angle of the true vernal equinox.
+pub fn apparent_sidereal_time(julian_day: f64) -> Angle {
+    // Equation from Astronomical Algorithms page 88
+    let julian_century = ops::julian_century(julian_day);
+    let mean_solar_longitude = ops::mean_solar_longitude(julian_century);
+    let mean_lunar_longitude = ops::mean_lunar_longitude(julian_century);
+    let ascending_lunar_node = ops::ascending_lunar_node_longitude(julian_century);
+    let nutation_longitude = ops::nutation_in_longitude(
+        mean_solar_longitude,
+        mean_lunar_longitude,
+        ascending_lunar_node,
+    );
+    let obliquity = ops::mean_obliquity_of_the_ecliptic(julian_century);
+
+    Angle::new(
+        ops::mean_sidereal_time(julian_century).degrees
+            + (nutation_longitude * obliquity.radians().cos()),
+    )
+    .unwound()
+}
+
+// The elongation of the moon from the sun in longitude, the
+// angle that is 0° at the new moon and 180° at the full moon.
+pub fn elongation_in_longitude(julian_day: f64) -> Angle {
+    let julian_century = ops::julian_century(julian_day);
+    let solar_longitude =
+        ops::apparent_solar_longitude(julian_century, ops::mean_solar_longitude(julian_century));
+
+    (LunarCoordinates::new(julian_day).longitude - solar_longitude).unwound()
+}
+
+// The time at which the elongation of the moon from the sun in
+// longitude is the given angle, nearest to the Julian ephemeris day.
+fn time_of_elongation(julian_day: f64, angle: Angle) -> f64 {
+    let mut julian_day = julian_day;
+
+    for _ in 0..10 {
+        let delta = (elongation_in_longitude(julian_day) - angle).quadrant_shifted();
+        let correction = delta.degrees * SYNODIC_MONTH / 360.0;
+
+        julian_day -= correction;
+
+        if correction.abs() < 0.00001 {
+            break;
+        } else {
+            // Nothing to do.
+        }
+    }
+
+    julian_day
+}
+
+// The time at, or before, the given time at which the elongation
+// of the moon from the sun in longitude is the given angle, e.g. 0°
+// for the new moon.
+pub fn phase_before(time: DateTime<Utc>, angle: Angle) -> Option<DateTime<Utc>> {
+    let ephemeris_day = julian_ephemeris_day(time);
+    let elongation = (elongation_in_longitude(ephemeris_day) - angle).unwound();
+    let estimate = ephemeris_day - (elongation.degrees * SYNODIC_MONTH / 360.0);
+    let mut phase = time_of_elongation(estimate, angle);
+
+    if phase > ephemeris_day {
+        phase = time_of_elongation(phase - SYNODIC_MONTH, angle);
+    } else {
+        // Nothing to do.
+    }
+
+    // Back to universal time.
+    time_from_julian_day(phase - (ephemeris_day - julian_day(time)))
+}
+
+// The time after the given time at which the elongation of the
+// moon from the sun in longitude is the given angle.
+pub fn phase_after(time: DateTime<Utc>, angle: Angle) -> Option<DateTime<Utc>> {
+    let ephemeris_day = julian_ephemeris_day(time);
+    let elongation = (angle - elongation_in_longitude(ephemeris_day)).unwound();
+    let estimate = ephemeris_day + (elongation.degrees * SYNODIC_MONTH / 360.0);
+    let mut phase = time_of_elongation(estimate, angle);
+
+    if phase <= ephemeris_day {
+        phase = time_of_elongation(phase + SYNODIC_MONTH, angle);
+    } else {
+        // Nothing to do.
+    }
+
+    // Back to universal time.
+    time_from_julian_day(phase - (ephemeris_day - julian_day(time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().expect("Invalid date provided")
+    }
+
+    #[test]
+    fn calculate_lunar_coordinates() {
+        // Example from Astronomical Algorithms page 342
+        let lunar = LunarCoordinates::new(2448724.5);
+
+        assert!((lunar.longitude.degrees - 133.167265).abs() < 0.0001);
+        assert!((lunar.latitude.degrees - -3.229126).abs() < 0.000001);
+        assert!((lunar.distance - 368409.7).abs() < 0.1);
+        assert!((lunar.right_ascension.degrees - 134.688470).abs() < 0.0001);
+        assert!((lunar.declination.degrees - 13.768368).abs() < 0.0001);
+        assert!((lunar.parallax().degrees - 0.991990).abs() < 0.000001);
+    }
+
+    #[test]
+    fn calculate_julian_days() {
+        let value = time("1992-04-12T12:00:00Z");
+
+        assert_eq!(julian_day(value), 2448725.0);
+        assert_eq!(time_from_julian_day(2448725.0), Some(value));
+        assert!((julian_ephemeris_day(value) - julian_day(value)) * 86400.0 > 58.0);
+    }
+
+    #[test]
+    fn calculate_new_moons() {
+        let new_moons = [
+            ("2000-01-06T18:14:00Z", "1999-12-20T00:00:00Z"),
+            ("2023-03-21T17:23:00Z", "2023-03-10T00:00:00Z"),
+            ("2024-04-08T18:21:00Z", "2024-04-01T12:00:00Z"),
+        ];
+
+        for (new_moon, before) in new_moons {
+            let new_moon = time(new_moon);
+            let after = phase_after(time(before), Angle::new(0.0)).expect("Invalid date provided");
+            let before = phase_before(new_moon + chrono::Duration::days(20), Angle::new(0.0))
+                .expect("Invalid date provided");
+
+            assert!((after - new_moon).num_seconds().abs() < 60, "{}", after);
+            assert_eq!(before, after);
+        }
+    }
+}
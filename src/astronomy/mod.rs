@@ -4,6 +4,7 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+pub mod lunar;
 pub mod ops;
 pub mod qiblah;
 pub mod solar;
@@ -66,6 +66,20 @@ pub fn solar_equation_of_the_center(julian_century: f64, mean_anomaly: Angle) ->
     Angle::new(term1 + term2 + term3)
 }
 
+// The distance between the centers of the Sun and
+// the Earth in astronomical units.
+pub fn solar_distance(julian_century: f64) -> f64 {
+    // Equations from Astronomical Algorithms page 163 and 164
+    let eccentricity = 0.016708634
+        - (0.000042037 * julian_century)
+        - (0.0000001267 * julian_century.powf(2.0));
+    let mean_anomaly = mean_solar_anomaly(julian_century);
+    let true_anomaly = mean_anomaly + solar_equation_of_the_center(julian_century, mean_anomaly);
+
+    (1.000001018 * (1.0 - eccentricity.powf(2.0)))
+        / (1.0 + (eccentricity * true_anomaly.radians().cos()))
+}
+
 // The apparent longitude of the Sun, referred to the
 // true equinox of the date.
 pub fn apparent_solar_longitude(julian_century: f64, mean_longitude: Angle) -> Angle {
@@ -159,6 +173,21 @@ pub fn altitude_of_celestial_body(
     Angle::from_radians((term1 + term2).asin())
 }
 
+// The azimuth of a celestial body, measured eastward from the north.
+pub fn azimuth_of_celestial_body(
+    observer_latitude: Angle,
+    declination: Angle,
+    local_hour_angle: Angle,
+) -> Angle {
+    // Equation from Astronomical Algorithms page 93
+    let term1 = local_hour_angle.radians().sin();
+    let term2 = (local_hour_angle.radians().cos() * observer_latitude.radians().sin())
+        - (declination.radians().tan() * observer_latitude.radians().cos());
+
+    // The equation measures the azimuth westward from the south.
+    Angle::new(Angle::from_radians(term1.atan2(term2)).degrees + 180.0).unwound()
+}
+
 pub fn approximate_transit(longitude: Angle, sidereal_time: Angle, right_ascension: Angle) -> f64 {
     // Equation from page Astronomical Algorithms 102
     let longitude_angle = longitude * Angle::new(-1.0);
@@ -303,6 +332,40 @@ pub fn julian_century(julian_day: f64) -> f64 {
     (julian_day - 2451545.0) / 36525.0
 }
 
+// The difference between the dynamical time and the
+// universal time (ΔT) in seconds, for the decimal year.
+pub fn delta_t(year: f64) -> f64 {
+    // Polynomial expressions by Espenak and Meeus, from the
+    // NASA Five Millennium Canon of Solar Eclipses
+    if (1900.0..1920.0).contains(&year) {
+        let t = year - 1900.0;
+        -2.79 + (1.494119 * t) - (0.0598939 * t.powf(2.0)) + (0.0061966 * t.powf(3.0))
+            - (0.000197 * t.powf(4.0))
+    } else if (1920.0..1941.0).contains(&year) {
+        let t = year - 1920.0;
+        21.20 + (0.84493 * t) - (0.076100 * t.powf(2.0)) + (0.0020936 * t.powf(3.0))
+    } else if (1941.0..1961.0).contains(&year) {
+        let t = year - 1950.0;
+        29.07 + (0.407 * t) - (t.powf(2.0) / 233.0) + (t.powf(3.0) / 2547.0)
+    } else if (1961.0..1986.0).contains(&year) {
+        let t = year - 1975.0;
+        45.45 + (1.067 * t) - (t.powf(2.0) / 260.0) - (t.powf(3.0) / 718.0)
+    } else if (1986.0..2005.0).contains(&year) {
+        let t = year - 2000.0;
+        63.86 + (0.3345 * t) - (0.060374 * t.powf(2.0)) + (0.0017275 * t.powf(3.0))
+            + (0.000651814 * t.powf(4.0)) + (0.00002373599 * t.powf(5.0))
+    } else if (2005.0..2050.0).contains(&year) {
+        let t = year - 2000.0;
+        62.92 + (0.32217 * t) + (0.005589 * t.powf(2.0))
+    } else if (2050.0..2150.0).contains(&year) {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + (32.0 * u.powf(2.0)) - (0.5628 * (2150.0 - year))
+    } else {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + (32.0 * u.powf(2.0))
+    }
+}
+
 // Checks if the given year is a leap year.
 pub fn is_leap_year(year: u32) -> bool {
     if year % 4 != 0 {
@@ -447,11 +510,6 @@ pub fn days_since_solstice(day_of_year: u32, year: u32, latitude: f64) -> u32 {
     }
 }
 
-pub fn adjust_time(date: &DateTime<Utc>, minutes: i64) -> DateTime<Utc> {
-    date.checked_add_signed(Duration::seconds(minutes * 60))
-        .unwrap()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -603,4 +661,32 @@ mod tests {
 
         assert_eq!(celestial_body.degrees, -0.90061562155943208);
     }
+
+    #[test]
+    fn calculate_azimuth_of_celestial_body() {
+        // Venus at Washington on 1987 April 10, 19h21m UT
+        // (Astronomical Algorithms page 95).
+        let azimuth = azimuth_of_celestial_body(
+            Angle::new(38.921389),
+            Angle::new(-6.719892),
+            Angle::new(64.352133),
+        );
+
+        assert!((azimuth.degrees - 248.0337).abs() < 0.0001);
+    }
+
+    #[test]
+    fn calculate_solar_distance() {
+        // Example from Astronomical Algorithms page 165
+        let julian_century = julian_century(2448908.5);
+
+        assert!((solar_distance(julian_century) - 0.99766).abs() < 0.00001);
+    }
+
+    #[test]
+    fn calculate_delta_t() {
+        assert!((delta_t(1990.0) - 56.9).abs() < 0.5);
+        assert!((delta_t(2000.0) - 63.8).abs() < 0.5);
+        assert!((delta_t(2023.0) - 69.2).abs() < 5.0);
+    }
 }
@@ -9,23 +9,30 @@ use chrono::{DateTime, Datelike, TimeZone, Utc};
 use crate::astronomy::ops;
 use crate::astronomy::unit::Stride;
 use crate::astronomy::unit::{Angle, Coordinates};
+use crate::error::Error;
 
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct SolarCoordinates {
     // The declination of the sun, the angle between
     // the rays of the Sun and the plane of the Earth's equator.
-    declination: Angle,
+    pub(crate) declination: Angle,
 
     // Right ascension of the Sun, the angular distance on the
     // celestial equator from the vernal equinox to the hour circle.
-    right_ascension: Angle,
+    pub(crate) right_ascension: Angle,
 
     // Apparent sidereal time, the hour angle of the vernal equinox.
     apparent_sidereal_time: Angle,
 }
 
 impl SolarCoordinates {
-    fn new(julian_day: f64) -> Self {
+    /// The solar coordinates at 0h UTC of the given date.
+    pub fn for_date(date: DateTime<Utc>) -> Self {
+        SolarCoordinates::new(date.julian_day())
+    }
+
+    /// The solar coordinates at the given Julian day.
+    pub(crate) fn new(julian_day: f64) -> Self {
         let julian_century = ops::julian_century(julian_day);
         let mean_solar_longitude = ops::mean_solar_longitude(julian_century);
         let mean_lunar_longitude = ops::mean_lunar_longitude(julian_century);
@@ -86,25 +93,36 @@ pub struct SolarTime {
     observer: Coordinates,
     solar: SolarCoordinates,
     pub transit: DateTime<Utc>,
-    pub sunrise: DateTime<Utc>,
-    pub sunset: DateTime<Utc>,
+    pub sunrise: Option<DateTime<Utc>>,
+    pub sunset: Option<DateTime<Utc>>,
     prev_solar: SolarCoordinates,
     next_solar: SolarCoordinates,
     approx_transit: f64,
 }
 
 impl SolarTime {
-    pub fn new(date: DateTime<Utc>, coordinates: Coordinates) -> SolarTime {
+    pub fn new(date: DateTime<Utc>, coordinates: Coordinates) -> Result<SolarTime, Error> {
         // All calculation need to occur at 0h0m UTC
         let today = Utc
             .with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
             .single()
             .expect("Invalid date received.");
-        let tomorrow = today.tomorrow();
-        let yesterday = today.yesterday();
-        let prev_solar = SolarCoordinates::new(yesterday.julian_day());
-        let solar = SolarCoordinates::new(today.julian_day());
-        let next_solar = SolarCoordinates::new(tomorrow.julian_day());
+        let prev_solar = SolarCoordinates::for_date(today.yesterday());
+        let solar = SolarCoordinates::for_date(today);
+        let next_solar = SolarCoordinates::for_date(today.tomorrow());
+
+        SolarTime::from_solar_coordinates(date, coordinates, prev_solar, solar, next_solar)
+    }
+
+    /// Calculates the solar time from the solar coordinates of the
+    /// day before, the day of, and the day after the given date.
+    pub fn from_solar_coordinates(
+        date: DateTime<Utc>,
+        coordinates: Coordinates,
+        prev_solar: SolarCoordinates,
+        solar: SolarCoordinates,
+        next_solar: SolarCoordinates,
+    ) -> Result<SolarTime, Error> {
         let solar_altitude = Angle::new(-50.0 / 60.0);
         let approx_transit = ops::approximate_transit(
             coordinates.longitude_angle(),
@@ -146,20 +164,31 @@ impl SolarTime {
             next_solar.declination,
         );
 
-        SolarTime {
+        let transit = SolarTime::setting_hour(transit_time, &date).ok_or(Error::DateOutOfRange)?;
+        let sunrise = SolarTime::setting_hour(sunrise_time, &date);
+        let sunset = SolarTime::setting_hour(sunset_time, &date);
+
+        Ok(SolarTime {
             date: date,
             observer: coordinates,
             solar: solar,
-            transit: SolarTime::setting_hour(transit_time, &date).unwrap(),
-            sunrise: SolarTime::setting_hour(sunrise_time, &date).unwrap(),
-            sunset: SolarTime::setting_hour(sunset_time, &date).unwrap(),
+            transit,
+            sunrise,
+            sunset,
             prev_solar: prev_solar,
             next_solar: next_solar,
             approx_transit: approx_transit,
-        }
+        })
+    }
+
+    /// Returns `true` when the sun both rises and sets on this date.
+    pub fn has_sunrise_and_sunset(&self) -> bool {
+        self.sunrise.is_some() && self.sunset.is_some()
     }
 
-    pub fn time_for_solar_angle(&self, angle: Angle, after_transit: bool) -> DateTime<Utc> {
+    /// Returns the time at which the sun reaches the given angle, or
+    /// `None` if the sun never reaches it on this date and location.
+    pub fn time_for_solar_angle(&self, angle: Angle, after_transit: bool) -> Option<DateTime<Utc>> {
         let hours = ops::corrected_hour_angle(
             self.approx_transit,
             angle,
@@ -174,16 +203,34 @@ impl SolarTime {
             self.next_solar.declination,
         );
 
-        SolarTime::setting_hour(hours, &self.date).unwrap()
+        // Near the highest (or lowest) point of the sun the correction
+        // can land on the wrong side of the transit, in which case the
+        // sun does not actually reach the angle.
+        SolarTime::setting_hour(hours, &self.date)
+            .filter(|time| (*time > self.transit) == after_transit)
+    }
+
+    pub fn afternoon(&self, shadow_length: f64) -> Option<DateTime<Utc>> {
+        // The shadow is undefined when the sun is below
+        // the horizon at its highest point.
+        if (self.observer.latitude - self.solar.declination.degrees).abs() >= 90.0 {
+            return None;
+        }
+
+        // Near the polar circles the shadow can stay longer
+        // than the sun is above the horizon.
+        self.time_for_solar_angle(self.afternoon_angle(shadow_length), true)
+            .filter(|time| self.sunset.is_none_or(|sunset| *time < sunset))
     }
 
-    pub fn afternoon(&self, shadow_length: f64) -> DateTime<Utc> {
+    // The angle of the sun when the shadow of an object
+    // reaches the given multiple of its length.
+    pub fn afternoon_angle(&self, shadow_length: f64) -> Angle {
         let absolute_degrees = (self.observer.latitude - self.solar.declination.degrees).abs();
         let tangent = Angle::new(absolute_degrees);
         let inverse = shadow_length + tangent.radians().tan();
-        let angle = Angle::from_radians((1.0 / inverse).atan());
 
-        self.time_for_solar_angle(angle, true)
+        Angle::from_radians((1.0 / inverse).atan())
     }
 
     fn setting_hour(value: f64, date: &DateTime<Utc>) -> Option<DateTime<Utc>> {
@@ -299,14 +346,14 @@ mod tests {
             .with_ymd_and_hms(2015, 7, 12, 0, 0, 0)
             .single()
             .expect("Invalid date and time provided");
-        let solar = SolarTime::new(date, coordinates);
+        let solar = SolarTime::new(date, coordinates).expect("Solar time could not be calculated");
         let transit_date = Utc.with_ymd_and_hms(2015, 07, 12, 17, 20, 0).unwrap();
         let sunrise_date = Utc.with_ymd_and_hms(2015, 07, 12, 10, 08, 0).unwrap();
         let sunset_date = Utc.with_ymd_and_hms(2015, 07, 13, 00, 32, 0).unwrap();
 
         assert_eq!(solar.transit, transit_date);
-        assert_eq!(solar.sunrise, sunrise_date);
-        assert_eq!(solar.sunset, sunset_date);
+        assert_eq!(solar.sunrise, Some(sunrise_date));
+        assert_eq!(solar.sunset, Some(sunset_date));
     }
 
     #[test]
@@ -316,10 +363,10 @@ mod tests {
             .with_ymd_and_hms(2015, 7, 12, 0, 0, 0)
             .single()
             .expect("Invalida date and time provided");
-        let solar = SolarTime::new(date, coordinates);
+        let solar = SolarTime::new(date, coordinates).expect("Solar time could not be calculated");
         let angle = Angle::new(-6.0);
-        let twilight_start = solar.time_for_solar_angle(angle, false);
-        let twilight_end = solar.time_for_solar_angle(angle, true);
+        let twilight_start = solar.time_for_solar_angle(angle, false).unwrap();
+        let twilight_end = solar.time_for_solar_angle(angle, true).unwrap();
 
         assert_eq!(twilight_start.format("%-k:%M").to_string(), "9:38");
         assert_eq!(twilight_end.format("%-k:%M").to_string(), "1:02");
@@ -72,6 +72,9 @@ impl<Tz: TimeZone> Stride for DateTime<Tz> {
 
                 adjusted + Duration::seconds(60 - adjusted_seconds)
             }
+            Rounding::Ihtiyat if seconds > 0 => adjusted + Duration::seconds(60 - seconds as i64),
+            Rounding::Ihtiyat => adjusted,
+            Rounding::Down => adjusted - Duration::seconds(seconds as i64),
             Rounding::None => adjusted,
         }
     }
@@ -200,6 +203,7 @@ impl Div for Angle {
 /// The latitude and longitude associated with a location.
 /// Both latiude and longitude values are specified in degrees.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinates {
     pub latitude: f64,
     pub longitude: f64,
@@ -215,6 +219,12 @@ impl Coordinates {
 }
 
 impl Coordinates {
+    /// Returns `true` when the latitude is within -90°...90° and
+    /// the longitude is within -180°...180°.
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude) && (-180.0..=180.0).contains(&self.longitude)
+    }
+
     pub fn latitude_angle(&self) -> Angle {
         Angle::new(self.latitude)
     }
@@ -318,6 +328,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn calculate_rounding_down() {
+        let time_1 = Utc
+            .with_ymd_and_hms(2015, 7, 13, 5, 59, 50)
+            .single()
+            .expect("Invalid date and time.");
+
+        assert_eq!(
+            time_1.rounded_minute(Rounding::Down),
+            Utc.with_ymd_and_hms(2015, 7, 13, 5, 59, 00)
+                .single()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn calculate_rounding_ihtiyat() {
+        let time_1 = Utc
+            .with_ymd_and_hms(2015, 7, 13, 5, 59, 20)
+            .single()
+            .expect("Invalid date and time.");
+
+        assert_eq!(
+            time_1.rounded_minute(Rounding::Ihtiyat),
+            Utc.with_ymd_and_hms(2015, 7, 13, 6, 00, 00)
+                .single()
+                .unwrap()
+        );
+        assert_eq!(
+            time_1
+                .with_second(0)
+                .unwrap()
+                .rounded_minute(Rounding::Ihtiyat),
+            Utc.with_ymd_and_hms(2015, 7, 13, 5, 59, 00)
+                .single()
+                .unwrap()
+        );
+        assert_eq!(
+            time_1.rounded_minute(Rounding::Ihtiyat.for_sunrise()),
+            Utc.with_ymd_and_hms(2015, 7, 13, 5, 59, 00)
+                .single()
+                .unwrap()
+        );
+    }
+
     #[test]
     fn calculate_rounding_none() {
         let time_1 = Utc
@@ -332,4 +387,17 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_coordinates_round_trip() {
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let json = serde_json::to_string(&coordinates).unwrap();
+
+        assert_eq!(json, r#"{"latitude":35.775,"longitude":-78.6336}"#);
+        assert_eq!(
+            serde_json::from_str::<Coordinates>(&json).unwrap(),
+            coordinates
+        );
+    }
 }
@@ -0,0 +1,238 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Prayer Calendar
+//!
+//! This module provides the prayer times for a range of dates,
+//! e.g. to build a monthly or yearly timetable.
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::astronomy::solar::{SolarCoordinates, SolarTime};
+use crate::astronomy::unit::{Coordinates, Stride};
+use crate::error::Error;
+use crate::models::parameters::Parameters;
+use crate::schedule::PrayerTimes;
+
+/// Calculates the prayer times for a range of dates.
+///
+/// The prayer times of a date depend on the position of the sun on
+/// that date and the days around it, so the neighbouring dates share
+/// most of their calculations. The calendar calculates each of them
+/// once, while the prayer times are the same as the ones that are
+/// calculated by [PrayerTimes](struct.PrayerTimes.html) for each date.
+pub struct PrayerCalendar;
+
+impl PrayerCalendar {
+    /// Returns the prayer times for every date from `start`
+    /// up to, and including, `end`.
+    pub fn range(
+        start: NaiveDate,
+        end: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<Vec<PrayerTimes>, Error> {
+        if !coordinates.is_valid() {
+            return Err(Error::InvalidCoordinates(coordinates));
+        }
+
+        if !PrayerTimes::is_in_range(start) || !PrayerTimes::is_in_range(end) {
+            return Err(Error::DateOutOfRange);
+        }
+
+        if start > end {
+            return Ok(Vec::new());
+        } else {
+            // Nothing to do.
+        }
+
+        let start_date = start
+            .and_hms_opt(0, 0, 0)
+            .ok_or(Error::DateOutOfRange)?
+            .and_utc();
+        let mut window = SolarWindow::new(start_date, coordinates);
+        let solar_time = window.next()?;
+        let mut solar_time_tomorrow = window.next()?;
+        let mut today = PrayerTimes::calculate_day(
+            start_date,
+            start,
+            solar_time,
+            solar_time_tomorrow,
+            coordinates,
+            parameters,
+        )?;
+        let mut prayer_times = Vec::new();
+        let mut date = start_date;
+
+        // Each day is calculated once, and is used both for its
+        // own prayer times and for the night of the day before.
+        loop {
+            let solar_time_after_tomorrow = window.next()?;
+            let tomorrow = PrayerTimes::calculate_day(
+                date.tomorrow(),
+                date.tomorrow().date_naive(),
+                solar_time_tomorrow,
+                solar_time_after_tomorrow,
+                coordinates,
+                parameters,
+            )?;
+
+            prayer_times.push(PrayerTimes::from_days(
+                date,
+                coordinates,
+                parameters,
+                today,
+                tomorrow,
+            )?);
+
+            if date.date_naive() >= end {
+                break;
+            } else {
+                solar_time_tomorrow = solar_time_after_tomorrow;
+                today = tomorrow;
+                date = date.tomorrow();
+            }
+        }
+
+        Ok(prayer_times)
+    }
+}
+
+// The solar times of consecutive days, where the solar
+// coordinates of each day are only calculated once.
+struct SolarWindow {
+    date: DateTime<Utc>,
+    coordinates: Coordinates,
+    solar_coordinates: [SolarCoordinates; 3],
+}
+
+impl SolarWindow {
+    fn new(date: DateTime<Utc>, coordinates: Coordinates) -> SolarWindow {
+        SolarWindow {
+            date,
+            coordinates,
+            solar_coordinates: [
+                SolarCoordinates::for_date(date.yesterday()),
+                SolarCoordinates::for_date(date),
+                SolarCoordinates::for_date(date.tomorrow()),
+            ],
+        }
+    }
+
+    // Returns the solar time of the current date and
+    // moves the window on to the next date.
+    fn next(&mut self) -> Result<SolarTime, Error> {
+        let [prev_solar, solar, next_solar] = self.solar_coordinates;
+        let solar_time = SolarTime::from_solar_coordinates(
+            self.date,
+            self.coordinates,
+            prev_solar,
+            solar,
+            next_solar,
+        )?;
+
+        self.date = self.date.tomorrow();
+        self.solar_coordinates = [
+            solar,
+            next_solar,
+            SolarCoordinates::for_date(self.date.tomorrow()),
+        ];
+
+        Ok(solar_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::madhab::Madhab;
+    use crate::models::method::Method;
+    use crate::models::parameters::Configuration;
+    use crate::models::polar_resolution::PolarResolution;
+
+    #[test]
+    fn calendar_matches_daily_prayer_times() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).expect("Invalid date provided");
+        let end = NaiveDate::from_ymd_opt(2024, 12, 31).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::MoonsightingCommittee, Madhab::Hanafi);
+        let calendar = PrayerCalendar::range(start, end, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(calendar.len(), 366);
+
+        for (prayer_times, date) in calendar.iter().zip(start.iter_days()) {
+            let expected = PrayerTimes::new(date, coordinates, params)
+                .expect("Prayer times could not be calculated");
+
+            assert_eq!(*prayer_times, expected);
+        }
+    }
+
+    #[test]
+    fn calendar_matches_daily_prayer_times_at_high_latitude() {
+        let start = NaiveDate::from_ymd_opt(2022, 5, 1).expect("Invalid date provided");
+        let end = NaiveDate::from_ymd_opt(2022, 8, 31).expect("Invalid date provided");
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let params = Configuration::new(18.0, 17.0)
+            .polar_resolution(PolarResolution::AqrabYaum)
+            .done();
+        let calendar = PrayerCalendar::range(start, end, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(calendar.len(), 123);
+
+        for (prayer_times, date) in calendar.iter().zip(start.iter_days()) {
+            let expected = PrayerTimes::new(date, coordinates, params)
+                .expect("Prayer times could not be calculated");
+
+            assert_eq!(*prayer_times, expected);
+        }
+    }
+
+    #[test]
+    fn calendar_for_a_single_day() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let calendar = PrayerCalendar::range(date, date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let expected = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(calendar, vec![expected]);
+    }
+
+    #[test]
+    fn calendar_for_an_empty_range() {
+        let start = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let end = NaiveDate::from_ymd_opt(2015, 7, 11).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+
+        assert_eq!(
+            PrayerCalendar::range(start, end, coordinates, params),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn calendar_with_invalid_input() {
+        let start = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let invalid_coordinates = Coordinates::new(95.0, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+
+        assert_eq!(
+            PrayerCalendar::range(start, start, invalid_coordinates, params),
+            Err(Error::InvalidCoordinates(invalid_coordinates))
+        );
+        assert_eq!(
+            PrayerCalendar::range(start, NaiveDate::MAX, coordinates, params),
+            Err(Error::DateOutOfRange)
+        );
+    }
+}
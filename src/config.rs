@@ -0,0 +1,377 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Configuration Files
+//!
+//! This module loads the parameters from TOML or JSON
+//! configuration files, starting from the parameters of a method
+//! and overriding any of the settings that are given in the file.
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::error::ParseError;
+use crate::models::adjustments::TimeAdjustment;
+use crate::models::method::Method;
+use crate::models::parameters::{Configuration, Parameters};
+use crate::models::validation::ParameterIssue;
+
+/// The errors that can occur while loading the parameters
+/// from a configuration file.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ConfigError {
+    /// The file could not be parsed, has a key that is not
+    /// known, or has a value of the wrong type.
+    Syntax(String),
+
+    /// A name does not match any of the values of its setting,
+    /// e.g. a method that is not known.
+    UnknownName(ParseError),
+
+    /// The parameters have an issue with a severity of `Error`, e.g. an
+    /// angle that is out of range. See [Parameters::validate](struct.Parameters.html#method.validate).
+    InvalidParameters(ParameterIssue),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Syntax(message) => write!(f, "Invalid configuration: {}", message),
+            ConfigError::UnknownName(error) => write!(f, "{}", error),
+            ConfigError::InvalidParameters(issue) => {
+                write!(f, "Invalid parameters: {}", issue)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<ParseError> for ConfigError {
+    fn from(error: ParseError) -> ConfigError {
+        ConfigError::UnknownName(error)
+    }
+}
+
+impl Configuration {
+    /// Returns the parameters that are described by the TOML
+    /// configuration, e.g.
+    ///
+    /// ```toml
+    /// method = "moonsighting_committee"
+    /// madhab = "hanafi"
+    /// high_latitude_rule = "seventh_of_the_night"
+    ///
+    /// [adjustments]
+    /// fajr = 2
+    /// ```
+    ///
+    /// The settings that are not given are the ones of the method,
+    /// or of `Method::Other` when no method is given. The settings
+    /// that are given are set as with the [Configuration](struct.Configuration.html)
+    /// builder, e.g. an `isha_angle` replaces the Isha interval of the
+    /// method, and an `isha_interval` replaces the Isha angle.
+    pub fn from_toml(text: &str) -> Result<Parameters, ConfigError> {
+        let settings: Settings = toml::from_str(text)
+            .map_err(|error| ConfigError::Syntax(error.to_string().trim_end().to_string()))?;
+
+        settings.parameters()
+    }
+
+    /// Returns the parameters that are described by the JSON
+    /// configuration. The keys are the same as for
+    /// [from_toml](struct.Configuration.html#method.from_toml).
+    pub fn from_json(text: &str) -> Result<Parameters, ConfigError> {
+        let settings: Settings =
+            serde_json::from_str(text).map_err(|error| ConfigError::Syntax(error.to_string()))?;
+
+        settings.parameters()
+    }
+}
+
+// The settings of a configuration file, where the names
+// are parsed separately for the errors to name the setting.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Settings {
+    method: Option<String>,
+    fajr_angle: Option<f64>,
+    maghrib_angle: Option<f64>,
+    isha_angle: Option<f64>,
+    isha_interval: Option<i32>,
+    ramadan_isha_interval: Option<i32>,
+    madhab: Option<String>,
+    high_latitude_rule: Option<String>,
+    polar_resolution: Option<String>,
+    night_start: Option<String>,
+    shafaq: Option<String>,
+    rounding: Option<String>,
+    authority_adjustments: Option<Adjustments>,
+    adjustments: Option<Adjustments>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Adjustments {
+    fajr: i64,
+    sunrise: i64,
+    dhuhr: i64,
+    asr: i64,
+    maghrib: i64,
+    isha: i64,
+}
+
+impl Adjustments {
+    fn time_adjustment(&self) -> TimeAdjustment {
+        TimeAdjustment::new(
+            self.fajr,
+            self.sunrise,
+            self.dhuhr,
+            self.asr,
+            self.maghrib,
+            self.isha,
+        )
+    }
+}
+
+impl Settings {
+    fn parameters(self) -> Result<Parameters, ConfigError> {
+        let method = match &self.method {
+            Some(name) => name.parse()?,
+            None => Method::Other,
+        };
+        let mut config = Configuration::from(method.parameters());
+
+        if let Some(angle) = self.fajr_angle {
+            config.fajr_angle(angle);
+        }
+        if let Some(angle) = self.maghrib_angle {
+            config.maghrib_angle(angle);
+        }
+        if let Some(angle) = self.isha_angle {
+            config.isha_angle(angle);
+        }
+        if let Some(interval) = self.isha_interval {
+            config.isha_interval(interval);
+        }
+        if let Some(interval) = self.ramadan_isha_interval {
+            config.ramadan_isha_interval(interval);
+        }
+        if let Some(name) = &self.madhab {
+            config.madhab(name.parse()?);
+        }
+        if let Some(name) = &self.high_latitude_rule {
+            config.high_latitude_rule(name.parse()?);
+        }
+        if let Some(name) = &self.polar_resolution {
+            config.polar_resolution(name.parse()?);
+        }
+        if let Some(name) = &self.night_start {
+            config.night_start(name.parse()?);
+        }
+        if let Some(name) = &self.shafaq {
+            config.shafaq(name.parse()?);
+        }
+        if let Some(name) = &self.rounding {
+            config.rounding(name.parse()?);
+        }
+        if let Some(adjustments) = self.authority_adjustments {
+            config.authority_adjustments(adjustments.time_adjustment());
+        }
+        if let Some(adjustments) = self.adjustments {
+            config.adjustments(adjustments.time_adjustment());
+        }
+
+        let params = config.done();
+
+        match params.validate().into_iter().find(|issue| issue.is_error()) {
+            Some(issue) => Err(ConfigError::InvalidParameters(issue)),
+            None => Ok(params),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astronomy::unit::Coordinates;
+    use crate::models::high_altitude_rule::HighLatitudeRule;
+    use crate::models::madhab::Madhab;
+    use crate::models::night_start::NightStart;
+    use crate::models::prayer::Prayer;
+    use crate::models::rounding::Rounding;
+    use crate::models::shafaq::Shafaq;
+    use crate::schedule::PrayerTimes;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn load_parameters_from_toml() {
+        let params = Configuration::from_toml(
+            r#"
+            method = "moonsighting_committee"
+            madhab = "hanafi"
+            high_latitude_rule = "seventh_of_the_night"
+            shafaq = "ahmer"
+            rounding = "up"
+
+            [authority_adjustments]
+            maghrib = 5
+
+            [adjustments]
+            fajr = 2
+            isha = -3
+            "#,
+        )
+        .expect("Configuration could not be loaded");
+
+        let mut expected = Configuration::with(Method::MoonsightingCommittee, Madhab::Hanafi);
+        expected.high_latitude_rule = HighLatitudeRule::SeventhOfTheNight;
+        expected.shafaq = Shafaq::Ahmer;
+        expected.rounding = Rounding::Up;
+        expected.authority_adjustments.maghrib = 5;
+        expected.adjustments.fajr = 2;
+        expected.adjustments.isha = -3;
+
+        assert_eq!(params, expected);
+    }
+
+    #[test]
+    fn load_parameters_from_json() {
+        let params = Configuration::from_json(
+            r#"{ "method": "umm_al_qura", "isha_interval": 120, "fajr_angle": 18.0 }"#,
+        )
+        .expect("Configuration could not be loaded");
+
+        assert_eq!(params.method, Method::UmmAlQura);
+        assert_eq!(params.fajr_angle, 18.0);
+        assert_eq!(params.isha_interval, 120);
+        assert_eq!(params.madhab, Madhab::Shafi);
+    }
+
+    #[test]
+    fn load_parameters_with_isha_angle_for_interval_method() {
+        let params = Configuration::from_toml("method = \"umm_al_qura\"\nisha_angle = 17.0")
+            .expect("Configuration could not be loaded");
+        let date = NaiveDate::from_ymd_opt(2023, 1, 15).expect("Invalid date provided");
+        let makkah = Coordinates::new(21.4225, 39.8262);
+        let times =
+            PrayerTimes::new(date, makkah, params).expect("Prayer times could not be calculated");
+        let interval_times = PrayerTimes::new(date, makkah, Method::UmmAlQura.parameters())
+            .expect("Prayer times could not be calculated");
+        let maghrib = times.time(Prayer::Maghrib).expect("Maghrib has no time");
+
+        assert_eq!(params.isha_angle, 17.0);
+        assert_eq!(params.isha_interval, 0);
+        assert_eq!(params.ramadan_isha_interval, 0);
+        assert!(params.validate().is_empty());
+        assert_eq!(
+            interval_times.time(Prayer::Isha),
+            Some(maghrib + chrono::Duration::minutes(90))
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Isha)
+                .expect("Isha has no time")
+                .format("%-l:%M %p")
+                .to_string(),
+            "4:13 PM"
+        );
+    }
+
+    #[test]
+    fn load_parameters_for_jafari_method() {
+        let params = Configuration::from_toml(
+            "method = \"jafari\"\nmadhab = \"hanafi\"\nnight_start = \"maghrib\"",
+        )
+        .expect("Configuration could not be loaded");
+        let date = NaiveDate::from_ymd_opt(2023, 6, 21).expect("Invalid date provided");
+        let qum = Coordinates::new(34.6401, 50.8764);
+        let times =
+            PrayerTimes::new(date, qum, params).expect("Prayer times could not be calculated");
+        let jafari = PrayerTimes::new(date, qum, Method::Jafari.parameters())
+            .expect("Prayer times could not be calculated");
+
+        // The Jafari method always uses the Shafi shadow for Asr.
+        assert_eq!(times.time(Prayer::Asr), jafari.time(Prayer::Asr));
+        assert_eq!(params.night_start, NightStart::Maghrib);
+    }
+
+    #[test]
+    fn load_parameters_with_ramadan_isha_interval() {
+        let params = Configuration::from_toml("method = \"qatar\"\nramadan_isha_interval = 105")
+            .expect("Configuration could not be loaded");
+
+        assert_eq!(params.isha_interval, 90);
+        assert_eq!(params.ramadan_isha_interval, 105);
+    }
+
+    #[test]
+    fn load_parameters_without_method() {
+        let params = Configuration::from_toml("fajr_angle = 18.0\nisha_angle = 17.0")
+            .expect("Configuration could not be loaded");
+
+        assert_eq!(params, Configuration::new(18.0, 17.0).done());
+    }
+
+    #[test]
+    fn load_parameters_with_unknown_key() {
+        let error = Configuration::from_toml("fajr_angel = 18.0").unwrap_err();
+
+        assert!(matches!(error, ConfigError::Syntax(_)));
+        assert!(error.to_string().contains("unknown field `fajr_angel`"));
+
+        let error = Configuration::from_json(r#"{ "adjustments": { "jumua": 5 } }"#).unwrap_err();
+
+        assert!(error.to_string().contains("unknown field `jumua`"));
+    }
+
+    #[test]
+    fn load_parameters_with_unknown_name() {
+        let error = Configuration::from_toml(r#"method = "moonsighting""#).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::UnknownName(ParseError::new("method", "moonsighting"))
+        );
+        assert_eq!(error.to_string(), "Unknown method: \"moonsighting\"");
+    }
+
+    #[test]
+    fn load_parameters_with_values_out_of_range() {
+        assert_eq!(
+            Configuration::from_toml("fajr_angle = 95.0\nisha_angle = 17.0"),
+            Err(ConfigError::InvalidParameters(
+                ParameterIssue::AngleOutOfRange {
+                    prayer: Prayer::Fajr,
+                    angle: 95.0
+                }
+            ))
+        );
+        assert_eq!(
+            Configuration::from_json(r#"{ "method": "qatar", "isha_interval": -30 }"#),
+            Err(ConfigError::InvalidParameters(
+                ParameterIssue::IshaIntervalOutOfRange(-30)
+            ))
+        );
+        assert_eq!(
+            Configuration::from_toml("method = \"karachi\"\n[adjustments]\nmaghrib = 2000")
+                .unwrap_err()
+                .to_string(),
+            "Invalid parameters: The adjustment of Maghrib is 2000 minutes"
+        );
+    }
+
+    #[test]
+    fn load_parameters_without_angles() {
+        assert_eq!(
+            Configuration::from_toml("madhab = \"hanafi\""),
+            Err(ConfigError::InvalidParameters(
+                ParameterIssue::MissingAngle(Prayer::Fajr)
+            ))
+        );
+    }
+}
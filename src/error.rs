@@ -0,0 +1,226 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use std::fmt;
+
+use crate::astronomy::unit::Coordinates;
+use crate::models::prayer::Prayer;
+use crate::models::validation::ParameterIssue;
+
+/// The errors that can occur while calculating the prayer times.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+    /// No date was provided to the [PrayerSchedule](struct.PrayerSchedule.html).
+    MissingDate,
+
+    /// No coordinates were provided to the [PrayerSchedule](struct.PrayerSchedule.html).
+    MissingCoordinates,
+
+    /// No parameters were provided to the [PrayerSchedule](struct.PrayerSchedule.html).
+    MissingParameters,
+
+    /// The latitude is not within -90°...90° or the
+    /// longitude is not within -180°...180°.
+    InvalidCoordinates(Coordinates),
+
+    /// The sun never reaches the angle (in degrees) that is needed
+    /// for the prayer on the given date and location.
+    SunNeverReachesAngle { prayer: Prayer, angle: f64 },
+
+    /// The date, or a time derived from it, is outside the range
+    /// that can be represented.
+    DateOutOfRange,
+
+    /// The time is before Fajr on the date of the prayer times, so
+    /// there is no current prayer. See [PrayerTimeline](struct.PrayerTimeline.html)
+    /// for the prayers that span across days.
+    BeforeFajr,
+
+    /// The parameters have an issue that keeps the prayer times from
+    /// being calculated. See [Parameters::validate](struct.Parameters.html#method.validate).
+    InvalidParameters(ParameterIssue),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingDate => write!(f, "A date is needed to calculate the prayer times"),
+            Error::MissingCoordinates => {
+                write!(f, "Coordinates are needed to calculate the prayer times")
+            }
+            Error::MissingParameters => {
+                write!(f, "Parameters are needed to calculate the prayer times")
+            }
+            Error::InvalidCoordinates(coordinates) => write!(
+                f,
+                "Invalid coordinates: latitude {}, longitude {}",
+                coordinates.latitude, coordinates.longitude
+            ),
+            Error::SunNeverReachesAngle { prayer, angle } => write!(
+                f,
+                "The sun never reaches {}° for {:?} on this date and location",
+                angle, prayer
+            ),
+            Error::DateOutOfRange => write!(f, "The date is out of range"),
+            Error::BeforeFajr => {
+                write!(f, "The time is before Fajr on the date of the prayer times")
+            }
+            Error::InvalidParameters(issue) => write!(f, "Invalid parameters: {}", issue),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The errors that can occur while registering a custom method
+/// with the [MethodRegistry](struct.MethodRegistry.html).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum RegistryError {
+    /// The id of the method is empty, or is
+    /// the name of a built-in method.
+    InvalidId,
+
+    /// A method with the same id, but with other
+    /// settings, has already been registered.
+    IdInUse,
+
+    /// The parameters of the method have an error. See
+    /// [Parameters::validate](struct.Parameters.html#method.validate).
+    InvalidParameters(ParameterIssue),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::InvalidId => write!(f, "The id of the method is not valid"),
+            RegistryError::IdInUse => {
+                write!(f, "A method with this id is registered with other settings")
+            }
+            RegistryError::InvalidParameters(issue) => {
+                write!(f, "Invalid parameters: {}", issue)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// The errors that can occur while calculating a [Crescent](struct.Crescent.html).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum CrescentError {
+    /// The latitude is not within -90°...90° or the
+    /// longitude is not within -180°...180°.
+    InvalidCoordinates(Coordinates),
+
+    /// The date, or a time derived from it, is outside the range
+    /// that can be represented.
+    DateOutOfRange,
+
+    /// The sun or the moon does not set on the evening
+    /// of the date, e.g. near the poles.
+    NoSunsetOrMoonset,
+}
+
+impl fmt::Display for CrescentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrescentError::InvalidCoordinates(coordinates) => write!(
+                f,
+                "Invalid coordinates: latitude {}, longitude {}",
+                coordinates.latitude, coordinates.longitude
+            ),
+            CrescentError::DateOutOfRange => write!(f, "The date is out of range"),
+            CrescentError::NoSunsetOrMoonset => write!(f, "The sun or the moon does not set"),
+        }
+    }
+}
+
+impl std::error::Error for CrescentError {}
+
+/// The error returned when a name does not match any of the values
+/// of a setting, e.g. when parsing a [Method](enum.Method.html).
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseError {
+    setting: &'static str,
+    name: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(setting: &'static str, name: &str) -> ParseError {
+        ParseError {
+            setting,
+            name: name.to_string(),
+        }
+    }
+
+    /// The name that could not be parsed.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown {}: \"{}\"", self.setting, self.name)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_description() {
+        let error = Error::InvalidCoordinates(Coordinates::new(95.0, 10.0));
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid coordinates: latitude 95, longitude 10"
+        );
+        assert_eq!(
+            Error::SunNeverReachesAngle {
+                prayer: Prayer::Isha,
+                angle: -18.0,
+            }
+            .to_string(),
+            "The sun never reaches -18° for Isha on this date and location"
+        );
+        assert_eq!(
+            Error::BeforeFajr.to_string(),
+            "The time is before Fajr on the date of the prayer times"
+        );
+        assert_eq!(
+            Error::DateOutOfRange.to_string(),
+            "The date is out of range"
+        );
+    }
+
+    #[test]
+    fn registry_error_description() {
+        assert_eq!(
+            RegistryError::IdInUse.to_string(),
+            "A method with this id is registered with other settings"
+        );
+    }
+
+    #[test]
+    fn crescent_error_description() {
+        assert_eq!(
+            CrescentError::NoSunsetOrMoonset.to_string(),
+            "The sun or the moon does not set"
+        );
+    }
+
+    #[test]
+    fn parse_error_description() {
+        let error = ParseError::new("method", "moonsighting");
+
+        assert_eq!(error.name(), "moonsighting");
+        assert_eq!(error.to_string(), "Unknown method: \"moonsighting\"");
+    }
+}
@@ -0,0 +1,280 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Hijri Calendar
+//!
+//! This module converts between the Gregorian and the Hijri dates,
+//! either on the Umm al-Qura calendar of Saudi Arabia or on the
+//! arithmetical (tabular) calendar.
+
+pub(crate) mod month;
+mod tabular;
+mod umm_al_qura;
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use self::month::HijriMonth;
+
+/// The calendar that is used for converting between
+/// the Gregorian and the Hijri dates.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HijriCalendar {
+    /// The official calendar of Saudi Arabia, by the published table
+    /// from 1356 to 1500 AH (1937 to 2077). The dates outside of the
+    /// table are converted with the `Tabular` calendar.
+    UmmAlQura,
+
+    /// The arithmetical calendar that is also known as the Kuwaiti
+    /// algorithm, with 11 leap years in each cycle of 30 years.
+    Tabular,
+}
+
+/// A date on the Hijri calendar.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HijriDate {
+    pub year: i32,
+    pub month: HijriMonth,
+    pub day: u32,
+}
+
+impl HijriDate {
+    pub fn new(year: i32, month: HijriMonth, day: u32) -> HijriDate {
+        HijriDate { year, month, day }
+    }
+
+    /// Returns the Hijri date of the Gregorian date.
+    pub fn from_gregorian(date: NaiveDate, calendar: HijriCalendar) -> HijriDate {
+        HijriDate::from_gregorian_with_offset(date, calendar, 0)
+    }
+
+    /// Returns the Hijri date of the Gregorian date, moved by the given
+    /// number of days, e.g. `1` or `-1` when the month started a day
+    /// earlier or later locally than on the calendar.
+    pub fn from_gregorian_with_offset(
+        date: NaiveDate,
+        calendar: HijriCalendar,
+        offset: i64,
+    ) -> HijriDate {
+        let days = date.num_days_from_ce() as i64 + offset;
+        let table = match calendar {
+            HijriCalendar::UmmAlQura => umm_al_qura::from_days(days),
+            HijriCalendar::Tabular => None,
+        };
+        let (year, month, day) = table.unwrap_or_else(|| tabular::from_days(days));
+
+        HijriDate { year, month, day }
+    }
+
+    /// Returns the Gregorian date of the Hijri date, or `None` when the
+    /// day is not in the month, e.g. the 30th of a month of 29 days.
+    pub fn to_gregorian(&self, calendar: HijriCalendar) -> Option<NaiveDate> {
+        if self.day < 1 || self.day > self.month_length(calendar) {
+            return None;
+        } else {
+            // Nothing to do.
+        }
+
+        let (year, month, day) = (self.year, self.month.number(), self.day);
+        let table = match calendar {
+            HijriCalendar::UmmAlQura => umm_al_qura::to_days(year, month, day),
+            HijriCalendar::Tabular => None,
+        };
+        let days = table.unwrap_or_else(|| tabular::to_days(year, month, day));
+
+        NaiveDate::from_num_days_from_ce_opt(i32::try_from(days).ok()?)
+    }
+
+    /// Returns the number of days in the month of the date, 29 or 30.
+    pub fn month_length(&self, calendar: HijriCalendar) -> u32 {
+        let month = self.month.number();
+        let table = match calendar {
+            HijriCalendar::UmmAlQura => umm_al_qura::month_length(self.year, month),
+            HijriCalendar::Tabular => None,
+        };
+
+        table.unwrap_or_else(|| tabular::month_length(self.year, month))
+    }
+}
+
+impl fmt::Display for HijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} AH", self.day, self.month.name(), self.year)
+    }
+}
+
+/// Converts the dates to the Hijri calendar.
+pub trait ToHijri {
+    fn to_hijri(&self, calendar: HijriCalendar) -> HijriDate;
+}
+
+impl ToHijri for NaiveDate {
+    fn to_hijri(&self, calendar: HijriCalendar) -> HijriDate {
+        HijriDate::from_gregorian(*self, calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date provided")
+    }
+
+    #[test]
+    fn convert_dates_on_umm_al_qura_calendar() {
+        let dates = [
+            (
+                date(1937, 3, 14),
+                HijriDate::new(1356, HijriMonth::Muharram, 1),
+            ),
+            (
+                date(2000, 1, 1),
+                HijriDate::new(1420, HijriMonth::Ramadan, 24),
+            ),
+            (
+                date(2022, 7, 30),
+                HijriDate::new(1444, HijriMonth::Muharram, 1),
+            ),
+            (
+                date(2023, 3, 22),
+                HijriDate::new(1444, HijriMonth::Shaban, 30),
+            ),
+            (
+                date(2023, 3, 23),
+                HijriDate::new(1444, HijriMonth::Ramadan, 1),
+            ),
+            (
+                date(2023, 4, 21),
+                HijriDate::new(1444, HijriMonth::Shawwal, 1),
+            ),
+            (
+                date(2024, 3, 11),
+                HijriDate::new(1445, HijriMonth::Ramadan, 1),
+            ),
+            (
+                date(2024, 4, 10),
+                HijriDate::new(1445, HijriMonth::Shawwal, 1),
+            ),
+            (
+                date(2077, 11, 16),
+                HijriDate::new(1500, HijriMonth::DhuAlHijjah, 30),
+            ),
+        ];
+
+        for (gregorian, hijri) in dates {
+            assert_eq!(gregorian.to_hijri(HijriCalendar::UmmAlQura), hijri);
+            assert_eq!(
+                hijri.to_gregorian(HijriCalendar::UmmAlQura),
+                Some(gregorian)
+            );
+        }
+    }
+
+    #[test]
+    fn convert_dates_on_tabular_calendar() {
+        let dates = [
+            (date(622, 7, 19), HijriDate::new(1, HijriMonth::Muharram, 1)),
+            (
+                date(1900, 1, 1),
+                HijriDate::new(1317, HijriMonth::Shaban, 28),
+            ),
+            (
+                date(1937, 3, 13),
+                HijriDate::new(1355, HijriMonth::DhuAlHijjah, 30),
+            ),
+            (
+                date(2000, 1, 1),
+                HijriDate::new(1420, HijriMonth::Ramadan, 24),
+            ),
+            (
+                date(2100, 6, 15),
+                HijriDate::new(1524, HijriMonth::RabiAlThani, 7),
+            ),
+            (
+                date(2500, 1, 1),
+                HijriDate::new(1936, HijriMonth::Muharram, 29),
+            ),
+        ];
+
+        for (gregorian, hijri) in dates {
+            assert_eq!(gregorian.to_hijri(HijriCalendar::Tabular), hijri);
+            assert_eq!(hijri.to_gregorian(HijriCalendar::Tabular), Some(gregorian));
+        }
+    }
+
+    #[test]
+    fn convert_dates_outside_of_umm_al_qura_table() {
+        for gregorian in [date(1900, 1, 1), date(1937, 3, 13), date(2100, 6, 15)] {
+            let hijri = gregorian.to_hijri(HijriCalendar::UmmAlQura);
+
+            assert_eq!(hijri, gregorian.to_hijri(HijriCalendar::Tabular));
+            assert_eq!(
+                hijri.to_gregorian(HijriCalendar::UmmAlQura),
+                Some(gregorian)
+            );
+        }
+    }
+
+    #[test]
+    fn convert_all_dates_back_and_forth() {
+        for calendar in [HijriCalendar::UmmAlQura, HijriCalendar::Tabular] {
+            for gregorian in date(1930, 1, 1)
+                .iter_days()
+                .take_while(|day| day.year() < 2085)
+            {
+                let hijri = gregorian.to_hijri(calendar);
+
+                assert_eq!(hijri.to_gregorian(calendar), Some(gregorian), "{}", hijri);
+            }
+        }
+    }
+
+    #[test]
+    fn convert_dates_with_offset() {
+        let calendar = HijriCalendar::UmmAlQura;
+
+        assert_eq!(
+            HijriDate::from_gregorian_with_offset(date(2023, 3, 22), calendar, 1),
+            HijriDate::new(1444, HijriMonth::Ramadan, 1)
+        );
+        assert_eq!(
+            HijriDate::from_gregorian_with_offset(date(2023, 3, 23), calendar, -2),
+            HijriDate::new(1444, HijriMonth::Shaban, 29)
+        );
+    }
+
+    #[test]
+    fn convert_invalid_dates() {
+        let calendar = HijriCalendar::UmmAlQura;
+
+        assert_eq!(
+            HijriDate::new(1444, HijriMonth::Ramadan, 30).month_length(calendar),
+            29
+        );
+        assert_eq!(
+            HijriDate::new(1444, HijriMonth::Ramadan, 30).to_gregorian(calendar),
+            None
+        );
+        assert_eq!(
+            HijriDate::new(1444, HijriMonth::Ramadan, 0).to_gregorian(calendar),
+            None
+        );
+    }
+
+    #[test]
+    fn display_dates() {
+        assert_eq!(
+            HijriDate::new(1444, HijriMonth::Ramadan, 1).to_string(),
+            "1 Ramadan 1444 AH"
+        );
+    }
+}
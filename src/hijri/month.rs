@@ -0,0 +1,115 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// The months of the Hijri year.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HijriMonth {
+    Muharram,
+    Safar,
+    RabiAlAwwal,
+    RabiAlThani,
+    JumadaAlUla,
+    JumadaAlAkhirah,
+    Rajab,
+    Shaban,
+    Ramadan,
+    Shawwal,
+    DhuAlQadah,
+    DhuAlHijjah,
+}
+
+pub(crate) const MONTHS: [HijriMonth; 12] = [
+    HijriMonth::Muharram,
+    HijriMonth::Safar,
+    HijriMonth::RabiAlAwwal,
+    HijriMonth::RabiAlThani,
+    HijriMonth::JumadaAlUla,
+    HijriMonth::JumadaAlAkhirah,
+    HijriMonth::Rajab,
+    HijriMonth::Shaban,
+    HijriMonth::Ramadan,
+    HijriMonth::Shawwal,
+    HijriMonth::DhuAlQadah,
+    HijriMonth::DhuAlHijjah,
+];
+
+impl HijriMonth {
+    /// Returns the month with the number, from 1 for Muharram
+    /// to 12 for Dhu al-Hijjah.
+    pub fn from_number(number: u32) -> Option<HijriMonth> {
+        MONTHS.get((number as usize).checked_sub(1)?).copied()
+    }
+
+    /// Returns the number of the month, from 1 for Muharram
+    /// to 12 for Dhu al-Hijjah.
+    pub fn number(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// Returns the name of the month transliterated into English.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HijriMonth::Muharram => "Muharram",
+            HijriMonth::Safar => "Safar",
+            HijriMonth::RabiAlAwwal => "Rabi al-Awwal",
+            HijriMonth::RabiAlThani => "Rabi al-Thani",
+            HijriMonth::JumadaAlUla => "Jumada al-Ula",
+            HijriMonth::JumadaAlAkhirah => "Jumada al-Akhirah",
+            HijriMonth::Rajab => "Rajab",
+            HijriMonth::Shaban => "Sha'ban",
+            HijriMonth::Ramadan => "Ramadan",
+            HijriMonth::Shawwal => "Shawwal",
+            HijriMonth::DhuAlQadah => "Dhu al-Qa'dah",
+            HijriMonth::DhuAlHijjah => "Dhu al-Hijjah",
+        }
+    }
+
+    /// Returns the name of the month in Arabic.
+    pub fn arabic_name(&self) -> &'static str {
+        match self {
+            HijriMonth::Muharram => "محرم",
+            HijriMonth::Safar => "صفر",
+            HijriMonth::RabiAlAwwal => "ربيع الأول",
+            HijriMonth::RabiAlThani => "ربيع الآخر",
+            HijriMonth::JumadaAlUla => "جمادى الأولى",
+            HijriMonth::JumadaAlAkhirah => "جمادى الآخرة",
+            HijriMonth::Rajab => "رجب",
+            HijriMonth::Shaban => "شعبان",
+            HijriMonth::Ramadan => "رمضان",
+            HijriMonth::Shawwal => "شوال",
+            HijriMonth::DhuAlQadah => "ذو القعدة",
+            HijriMonth::DhuAlHijjah => "ذو الحجة",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn month_numbers() {
+        for number in 1..=12 {
+            let month = HijriMonth::from_number(number).expect("Invalid month number");
+
+            assert_eq!(month.number(), number);
+        }
+
+        assert_eq!(HijriMonth::from_number(9), Some(HijriMonth::Ramadan));
+        assert_eq!(HijriMonth::from_number(0), None);
+        assert_eq!(HijriMonth::from_number(13), None);
+    }
+
+    #[test]
+    fn month_names() {
+        assert_eq!(HijriMonth::Ramadan.name(), "Ramadan");
+        assert_eq!(HijriMonth::Ramadan.arabic_name(), "رمضان");
+        assert_eq!(HijriMonth::DhuAlHijjah.name(), "Dhu al-Hijjah");
+        assert_eq!(HijriMonth::DhuAlHijjah.arabic_name(), "ذو الحجة");
+    }
+}
@@ -0,0 +1,74 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+// The arithmetical (Kuwaiti) calendar, with 11 leap years in each
+// cycle of 30 years and the civil epoch of 16 July 622 (Julian).
+
+use super::month::{HijriMonth, MONTHS};
+
+// The days from the common era of 1 Muharram 1 AH, which
+// is 19 July 622 on the proleptic Gregorian calendar.
+const EPOCH: i64 = 227_015;
+
+fn is_leap_year(year: i64) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+// The days from the epoch to the first day of the year.
+fn days_before_year(year: i64) -> i64 {
+    (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+// The days from the first day of the year to the first day of the month,
+// where the odd months have 30 days and the even months have 29 days.
+fn days_before_month(month: u32) -> i64 {
+    29 * (month as i64 - 1) + (month as i64) / 2
+}
+
+pub(crate) fn month_length(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year as i64)) {
+        30
+    } else {
+        29
+    }
+}
+
+// Returns the year, month and day for the days from the common era.
+pub(crate) fn from_days(days: i64) -> (i32, HijriMonth, u32) {
+    let days = days - EPOCH;
+    let mut year = (days * 30).div_euclid(10_631) + 1;
+
+    while days_before_year(year + 1) <= days {
+        year += 1;
+    }
+    while days_before_year(year) > days {
+        year -= 1;
+    }
+
+    let mut day = days - days_before_year(year);
+    let mut month = HijriMonth::Muharram;
+
+    // The days that are left after the eleventh month are all in
+    // Dhu al-Hijjah, which is the last month of the year.
+    for next in MONTHS {
+        month = next;
+
+        let length = month_length(year as i32, month.number()) as i64;
+
+        if day < length || month == HijriMonth::DhuAlHijjah {
+            break;
+        } else {
+            day -= length;
+        }
+    }
+
+    (year as i32, month, day as u32 + 1)
+}
+
+// Returns the days from the common era for the year, month and day.
+pub(crate) fn to_days(year: i32, month: u32, day: u32) -> i64 {
+    EPOCH + days_before_year(year as i64) + days_before_month(month) + day as i64 - 1
+}
@@ -0,0 +1,237 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use super::month::{HijriMonth, MONTHS as HIJRI_MONTHS};
+
+// The first year of the table, and the days from the common
+// era of its first day, which is 14 March 1937.
+const FIRST_YEAR: i32 = 1356;
+const FIRST_DAY: i64 = 707_182;
+
+// The lengths of the months of the Umm al-Qura calendar for each
+// year from 1356 to 1500 AH, where the bit of a month is set when
+// it has 30 days, with Muharram as the lowest bit.
+const MONTHS: [u16; 145] = [
+    0b1110_1010_1010, // 1356
+    0b1110_1001_0100, // 1357
+    0b1101_0010_1010, // 1358
+    0b1100_0101_0110, // 1359
+    0b0100_1010_1110, // 1360
+    0b1010_0110_1101, // 1361
+    0b0101_0110_1010, // 1362
+    0b1101_0101_0101, // 1363
+    0b1101_0100_1010, // 1364
+    0b1010_1001_0011, // 1365
+    0b0101_0010_1011, // 1366
+    0b1010_0101_1011, // 1367
+    0b0101_0011_1010, // 1368
+    0b0110_1011_0101, // 1369
+    0b1110_1010_1001, // 1370
+    0b1101_0101_0010, // 1371
+    0b1101_0010_1001, // 1372
+    0b1010_0101_0101, // 1373
+    0b0100_1010_1101, // 1374
+    0b0101_0110_1101, // 1375
+    0b1010_1110_1010, // 1376
+    0b0110_1110_0100, // 1377
+    0b1110_1101_0001, // 1378
+    0b1101_1010_0010, // 1379
+    0b1010_1010_1010, // 1380
+    0b1001_0101_1010, // 1381
+    0b0010_1101_1010, // 1382
+    0b0101_1011_1001, // 1383
+    0b1011_1011_0010, // 1384
+    0b0111_0110_0100, // 1385
+    0b0110_1100_1001, // 1386
+    0b0101_0101_0101, // 1387
+    0b0010_1010_1011, // 1388
+    0b0100_1101_1011, // 1389
+    0b1010_1011_1010, // 1390
+    0b0101_1011_0100, // 1391
+    0b1101_1010_1001, // 1392
+    0b1101_0101_0010, // 1393
+    0b1010_1010_0101, // 1394
+    0b1001_0010_1101, // 1395
+    0b0010_0110_1101, // 1396
+    0b1000_1110_1101, // 1397
+    0b0010_1101_1010, // 1398
+    0b1010_1101_0101, // 1399
+    0b1010_1010_0101, // 1400
+    0b1010_0100_1011, // 1401
+    0b0100_1001_0111, // 1402
+    0b1001_0011_0111, // 1403
+    0b0010_1011_0110, // 1404
+    0b1001_0111_0101, // 1405
+    0b1101_0110_1001, // 1406
+    0b1101_0101_0010, // 1407
+    0b1100_1001_0101, // 1408
+    0b1001_0010_1011, // 1409
+    0b0010_0101_1011, // 1410
+    0b0100_1101_1011, // 1411
+    0b1001_1101_0101, // 1412
+    0b0101_1101_0010, // 1413
+    0b1101_1010_0101, // 1414
+    0b1101_0100_1010, // 1415
+    0b1010_1001_0101, // 1416
+    0b0101_0100_1101, // 1417
+    0b1010_1010_1101, // 1418
+    0b0011_1010_1010, // 1419
+    0b1011_1101_0010, // 1420
+    0b1011_1100_0100, // 1421
+    0b1011_1000_1001, // 1422
+    0b1010_1001_0101, // 1423
+    0b0101_0010_1101, // 1424
+    0b0101_1010_1101, // 1425
+    0b1011_0110_1010, // 1426
+    0b0110_1101_0100, // 1427
+    0b1101_1100_1001, // 1428
+    0b1101_1001_0010, // 1429
+    0b1010_1010_0110, // 1430
+    0b1001_0101_0110, // 1431
+    0b0010_1010_1110, // 1432
+    0b0101_0110_1101, // 1433
+    0b0011_0110_1010, // 1434
+    0b1011_0101_0101, // 1435
+    0b1010_1010_1010, // 1436
+    0b1001_0100_1101, // 1437
+    0b0100_1001_1101, // 1438
+    0b1001_0101_1101, // 1439
+    0b0010_1011_1010, // 1440
+    0b0101_1011_0101, // 1441
+    0b0101_1010_1010, // 1442
+    0b1101_0101_0101, // 1443
+    0b1010_1001_1010, // 1444
+    0b1001_0010_1110, // 1445
+    0b0010_0110_1110, // 1446
+    0b0101_0101_1101, // 1447
+    0b1010_1101_1010, // 1448
+    0b0110_1101_0100, // 1449
+    0b0110_1010_0101, // 1450
+    0b1011_0010_0111, // 1451
+    0b1010_0100_1101, // 1452
+    0b0100_1010_1101, // 1453
+    0b0101_0110_1101, // 1454
+    0b1011_0101_1010, // 1455
+    0b0111_0101_0100, // 1456
+    0b1111_0100_1001, // 1457
+    0b1110_1001_0010, // 1458
+    0b1101_0010_0110, // 1459
+    0b1010_0101_0110, // 1460
+    0b0011_0101_0110, // 1461
+    0b0110_1011_0101, // 1462
+    0b1011_1010_1010, // 1463
+    0b1011_1001_0010, // 1464
+    0b1011_0010_0101, // 1465
+    0b0110_1000_1011, // 1466
+    0b1010_1001_1011, // 1467
+    0b0101_0101_1010, // 1468
+    0b1010_1101_1010, // 1469
+    0b0101_1011_0100, // 1470
+    0b1101_1010_1001, // 1471
+    0b1011_0101_0010, // 1472
+    0b1010_1001_1010, // 1473
+    0b0101_0011_0110, // 1474
+    0b0010_0111_0110, // 1475
+    0b0101_0111_0101, // 1476
+    0b1010_1111_0010, // 1477
+    0b0110_1101_0100, // 1478
+    0b0110_1010_1001, // 1479
+    0b0101_0101_0101, // 1480
+    0b0010_1010_1101, // 1481
+    0b0100_1011_1101, // 1482
+    0b1001_1011_1010, // 1483
+    0b0101_0111_0100, // 1484
+    0b1011_0110_1001, // 1485
+    0b1011_0101_0010, // 1486
+    0b1010_1001_0101, // 1487
+    0b0101_0010_1101, // 1488
+    0b1010_0101_1101, // 1489
+    0b0100_1101_1010, // 1490
+    0b1010_1101_1001, // 1491
+    0b0110_1011_0010, // 1492
+    0b1110_1001_0101, // 1493
+    0b1110_0010_1010, // 1494
+    0b1100_1001_0110, // 1495
+    0b1001_0010_1110, // 1496
+    0b1010_1010_1101, // 1497
+    0b0101_0110_1010, // 1498
+    0b1101_0110_0101, // 1499
+    0b1101_0100_1010, // 1500
+];
+
+// The days from the common era of the first day of each
+// year of the table, and of the day after the table.
+const YEAR_STARTS: [i64; 146] = year_starts();
+
+const fn year_starts() -> [i64; 146] {
+    let mut starts = [FIRST_DAY; 146];
+    let mut year = 0;
+
+    while year < MONTHS.len() {
+        // Each year has 354 days, and a day more for each month of 30 days.
+        starts[year + 1] = starts[year] + 354 + MONTHS[year].count_ones() as i64 - 6;
+        year += 1;
+    }
+
+    starts
+}
+
+fn year_months(year: i32) -> Option<u16> {
+    MONTHS
+        .get(usize::try_from(year - FIRST_YEAR).ok()?)
+        .copied()
+}
+
+// Returns the length of the month, or `None` when
+// the year is outside of the range of the table.
+pub(crate) fn month_length(year: i32, month: u32) -> Option<u32> {
+    let year_months = year_months(year)?;
+
+    if year_months & (1 << (month - 1)) != 0 {
+        Some(30)
+    } else {
+        Some(29)
+    }
+}
+
+// Returns the year, month and day for the days from the common
+// era, or `None` when they are outside of the range of the table.
+pub(crate) fn from_days(days: i64) -> Option<(i32, HijriMonth, u32)> {
+    if !(FIRST_DAY..YEAR_STARTS[MONTHS.len()]).contains(&days) {
+        return None;
+    } else {
+        // Nothing to do.
+    }
+
+    let index = YEAR_STARTS.partition_point(|&start| start <= days) - 1;
+    let year = FIRST_YEAR + index as i32;
+    let mut day = days - YEAR_STARTS[index];
+
+    for month in HIJRI_MONTHS {
+        let length = month_length(year, month.number())? as i64;
+
+        if day < length {
+            return Some((year, month, day as u32 + 1));
+        } else {
+            day -= length;
+        }
+    }
+
+    None
+}
+
+// Returns the days from the common era for the year, month and day, or
+// `None` when the year is outside of the range of the table.
+pub(crate) fn to_days(year: i32, month: u32, day: u32) -> Option<i64> {
+    year_months(year)?;
+
+    let year_start = YEAR_STARTS[(year - FIRST_YEAR) as usize];
+    let days_before_month: i64 = (1..month)
+        .map(|month| month_length(year, month).map(i64::from))
+        .sum::<Option<i64>>()?;
+
+    Some(year_start + days_before_month + day as i64 - 1)
+}
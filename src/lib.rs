@@ -24,19 +24,51 @@
 //! ```
 
 mod astronomy;
+mod calendar;
+#[cfg(feature = "config")]
+mod config;
+mod error;
+mod hijri;
 mod models;
+mod moon;
 mod schedule;
+mod timeline;
+mod zoned;
 
 pub use crate::astronomy::unit::{Coordinates, Stride};
-pub use crate::models::adjustments::{Adjustment, TimeAdjustment};
+pub use crate::calendar::PrayerCalendar;
+#[cfg(feature = "config")]
+pub use crate::config::ConfigError;
+pub use crate::error::{CrescentError, Error, ParseError, RegistryError};
+pub use crate::hijri::month::HijriMonth;
+pub use crate::hijri::{HijriCalendar, HijriDate, ToHijri};
+pub use crate::models::adjustments::{Adjustment, AdjustmentLayers, TimeAdjustment};
+pub use crate::models::high_altitude_rule::HighLatitudeRule;
 pub use crate::models::madhab::Madhab;
 pub use crate::models::method::Method;
+pub use crate::models::method_info::{MethodInfo, TimeDefinition, TwilightModel};
+pub use crate::models::night_start::NightStart;
 pub use crate::models::parameters::{Configuration, Parameters};
+pub use crate::models::polar_resolution::PolarResolution;
 pub use crate::models::prayer::Prayer;
+pub use crate::models::registry::{CustomMethod, MethodId, MethodRegistry};
+pub use crate::models::rounding::Rounding;
+pub use crate::models::shafaq::Shafaq;
+pub use crate::models::status::PrayerStatus;
+pub use crate::models::validation::{ParameterIssue, Severity};
+pub use crate::moon::crescent::{Crescent, OdehVisibility, YallopVisibility};
+pub use crate::moon::grid::VisibilityGrid;
+pub use crate::moon::phase::MoonPhase;
+pub use crate::moon::position::{Moon, MoonPosition};
+pub use crate::moon::times::MoonTimes;
 pub use crate::schedule::{PrayerSchedule, PrayerTimes};
+pub use crate::timeline::{PrayerEvents, PrayerTimeline};
+pub use crate::zoned::ZonedPrayerTimes;
 pub use chrono::{
-    DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Timelike, Utc, Weekday,
+    DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, TimeZone, Timelike, Utc, Weekday,
 };
+#[cfg(feature = "chrono-tz")]
+pub use chrono_tz::Tz;
 
 /// A convenience module appropriate for glob imports (`use salah::prelude::*;`).
 pub mod prelude {
@@ -45,64 +77,129 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use crate::astronomy::unit::{Coordinates, Stride};
     #[doc(no_inline)]
-    pub use crate::models::adjustments::{Adjustment, TimeAdjustment};
+    pub use crate::calendar::PrayerCalendar;
+    #[cfg(feature = "config")]
+    #[doc(no_inline)]
+    pub use crate::config::ConfigError;
+    #[doc(no_inline)]
+    pub use crate::error::{CrescentError, Error, ParseError, RegistryError};
+    #[doc(no_inline)]
+    pub use crate::hijri::month::HijriMonth;
+    #[doc(no_inline)]
+    pub use crate::hijri::{HijriCalendar, HijriDate, ToHijri};
+    #[doc(no_inline)]
+    pub use crate::models::adjustments::{Adjustment, AdjustmentLayers, TimeAdjustment};
+    #[doc(no_inline)]
+    pub use crate::models::high_altitude_rule::HighLatitudeRule;
     #[doc(no_inline)]
     pub use crate::models::madhab::Madhab;
     #[doc(no_inline)]
     pub use crate::models::method::Method;
     #[doc(no_inline)]
+    pub use crate::models::method_info::{MethodInfo, TimeDefinition, TwilightModel};
+    #[doc(no_inline)]
+    pub use crate::models::night_start::NightStart;
+    #[doc(no_inline)]
     pub use crate::models::parameters::{Configuration, Parameters};
     #[doc(no_inline)]
+    pub use crate::models::polar_resolution::PolarResolution;
+    #[doc(no_inline)]
     pub use crate::models::prayer::Prayer;
     #[doc(no_inline)]
+    pub use crate::models::registry::{CustomMethod, MethodId, MethodRegistry};
+    #[doc(no_inline)]
+    pub use crate::models::rounding::Rounding;
+    #[doc(no_inline)]
+    pub use crate::models::shafaq::Shafaq;
+    #[doc(no_inline)]
+    pub use crate::models::status::PrayerStatus;
+    #[doc(no_inline)]
+    pub use crate::models::validation::{ParameterIssue, Severity};
+    #[doc(no_inline)]
+    pub use crate::moon::crescent::{Crescent, OdehVisibility, YallopVisibility};
+    #[doc(no_inline)]
+    pub use crate::moon::grid::VisibilityGrid;
+    #[doc(no_inline)]
+    pub use crate::moon::phase::MoonPhase;
+    #[doc(no_inline)]
+    pub use crate::moon::position::{Moon, MoonPosition};
+    #[doc(no_inline)]
+    pub use crate::moon::times::MoonTimes;
+    #[doc(no_inline)]
     pub use crate::schedule::{PrayerSchedule, PrayerTimes};
     #[doc(no_inline)]
+    pub use crate::timeline::{PrayerEvents, PrayerTimeline};
+    #[doc(no_inline)]
+    pub use crate::zoned::ZonedPrayerTimes;
+    #[doc(no_inline)]
     pub use chrono::{
-        DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Timelike, Utc, Weekday,
+        DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, TimeZone, Timelike, Utc,
+        Weekday,
     };
+    #[cfg(feature = "chrono-tz")]
+    #[doc(no_inline)]
+    pub use chrono_tz::Tz;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::high_altitude_rule::HighLatitudeRule;
-    use chrono::prelude::*;
 
     #[test]
     fn calculate_prayer_times() {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let schedule = PrayerTimes::new(local_date, coordinates, params);
+        let schedule = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
 
         assert_eq!(
-            schedule.time(Prayer::Fajr).format("%-l:%M %p").to_string(),
+            schedule
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
             "8:42 AM"
         );
         assert_eq!(
             schedule
                 .time(Prayer::Sunrise)
+                .unwrap()
                 .format("%-l:%M %p")
                 .to_string(),
             "10:08 AM"
         );
         assert_eq!(
-            schedule.time(Prayer::Dhuhr).format("%-l:%M %p").to_string(),
+            schedule
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
             "5:21 PM"
         );
         assert_eq!(
-            schedule.time(Prayer::Asr).format("%-l:%M %p").to_string(),
+            schedule
+                .time(Prayer::Asr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
             "10:22 PM"
         );
         assert_eq!(
             schedule
                 .time(Prayer::Maghrib)
+                .unwrap()
                 .format("%-l:%M %p")
                 .to_string(),
             "12:32 AM"
         );
         assert_eq!(
-            schedule.time(Prayer::Isha).format("%-l:%M %p").to_string(),
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
             "1:57 AM"
         );
     }
@@ -121,33 +218,51 @@ mod tests {
         match result {
             Ok(schedule) => {
                 assert_eq!(
-                    schedule.time(Prayer::Fajr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Fajr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "8:42 AM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Sunrise)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "10:08 AM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Dhuhr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Dhuhr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "5:21 PM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Asr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Asr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "10:22 PM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Maghrib)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "12:32 AM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Isha).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Isha)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "1:57 AM"
                 );
             }
@@ -165,10 +280,7 @@ mod tests {
             .with_configuration(params)
             .calculate();
 
-        assert!(
-            result.is_err(),
-            "We were expecting an error, but received data."
-        );
+        assert_eq!(result, Err(Error::MissingCoordinates));
     }
 
     #[test]
@@ -191,12 +303,17 @@ mod tests {
                 assert_eq!(
                     schedule
                         .time(Prayer::Maghrib)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "12:32 AM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Qiyam).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Qiyam)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "5:59 AM"
                 );
             }
@@ -220,12 +337,30 @@ mod tests {
             Ok(schedule) => {
                 let hour = 3600;
                 let sgt_offset = FixedOffset::east_opt(8 * hour).expect("Invalid offset provided");
-                let sgt_fajr = schedule.time(Prayer::Fajr).with_timezone(&sgt_offset);
-                let sgt_sunrise = schedule.time(Prayer::Sunrise).with_timezone(&sgt_offset);
-                let sgt_dhuhr = schedule.time(Prayer::Dhuhr).with_timezone(&sgt_offset);
-                let sgt_asr = schedule.time(Prayer::Asr).with_timezone(&sgt_offset);
-                let sgt_maghrib = schedule.time(Prayer::Maghrib).with_timezone(&sgt_offset);
-                let sgt_isha = schedule.time(Prayer::Isha).with_timezone(&sgt_offset);
+                let sgt_fajr = schedule
+                    .time(Prayer::Fajr)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
+                let sgt_sunrise = schedule
+                    .time(Prayer::Sunrise)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
+                let sgt_dhuhr = schedule
+                    .time(Prayer::Dhuhr)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
+                let sgt_asr = schedule
+                    .time(Prayer::Asr)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
+                let sgt_maghrib = schedule
+                    .time(Prayer::Maghrib)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
+                let sgt_isha = schedule
+                    .time(Prayer::Isha)
+                    .unwrap()
+                    .with_timezone(&sgt_offset);
 
                 assert_eq!(sgt_fajr.format("%-l:%M %p").to_string(), "5:50 AM");
                 assert_eq!(sgt_sunrise.format("%-l:%M %p").to_string(), "7:13 AM");
@@ -269,12 +404,30 @@ mod tests {
             Ok(schedule) => {
                 let hour = 3600;
                 let wib_offset = FixedOffset::east_opt(7 * hour).expect("Invalid offset provided");
-                let wib_fajr = schedule.time(Prayer::Fajr).with_timezone(&wib_offset);
-                let wib_sunrise = schedule.time(Prayer::Sunrise).with_timezone(&wib_offset);
-                let wib_dhuhr = schedule.time(Prayer::Dhuhr).with_timezone(&wib_offset);
-                let wib_asr = schedule.time(Prayer::Asr).with_timezone(&wib_offset);
-                let wib_maghrib = schedule.time(Prayer::Maghrib).with_timezone(&wib_offset);
-                let wib_isha = schedule.time(Prayer::Isha).with_timezone(&wib_offset);
+                let wib_fajr = schedule
+                    .time(Prayer::Fajr)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
+                let wib_sunrise = schedule
+                    .time(Prayer::Sunrise)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
+                let wib_dhuhr = schedule
+                    .time(Prayer::Dhuhr)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
+                let wib_asr = schedule
+                    .time(Prayer::Asr)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
+                let wib_maghrib = schedule
+                    .time(Prayer::Maghrib)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
+                let wib_isha = schedule
+                    .time(Prayer::Isha)
+                    .unwrap()
+                    .with_timezone(&wib_offset);
 
                 assert_eq!(wib_fajr.format("%-l:%M %p").to_string(), "4:15 AM");
                 assert_eq!(wib_sunrise.format("%-l:%M %p").to_string(), "5:45 AM");
@@ -287,6 +440,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calculate_times_for_maghreb_methods() {
+        // These are regression values of this calculation. They have not
+        // been checked against the timetables of the Habous ministry or
+        // the religious affairs ministries of Algeria and Tunisia.
+        let cet_offset = FixedOffset::east_opt(3600).expect("Invalid offset provided");
+        let cities = [
+            // Rabat
+            (
+                Method::Morocco,
+                Coordinates::new(34.0209, -6.8416),
+                [
+                    "6:29 AM", "7:57 AM", "1:45 PM", "4:55 PM", "7:28 PM", "8:42 PM",
+                ],
+            ),
+            // Algiers
+            (
+                Method::Algeria,
+                Coordinates::new(36.7538, 3.0588),
+                [
+                    "5:53 AM", "7:19 AM", "1:00 PM", "4:13 PM", "6:45 PM", "8:03 PM",
+                ],
+            ),
+            // Tunis
+            (
+                Method::Tunisia,
+                Coordinates::new(36.8065, 10.1815),
+                [
+                    "5:25 AM", "6:51 AM", "12:32 PM", "3:45 PM", "6:13 PM", "7:39 PM",
+                ],
+            ),
+        ];
+        let date = NaiveDate::from_ymd_opt(2023, 3, 1).expect("Invalid date provided");
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+
+        for (method, coordinates, expected) in cities {
+            let schedule = PrayerSchedule::new()
+                .on(date)
+                .for_location(coordinates)
+                .with_configuration(method.parameters())
+                .calculate_in(cet_offset)
+                .expect("Prayer times could not be calculated");
+
+            for (prayer, expected) in prayers.into_iter().zip(expected) {
+                assert_eq!(
+                    schedule
+                        .time(prayer)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
+                    expected,
+                    "{:?} of {:?}",
+                    prayer,
+                    method
+                );
+            }
+        }
+    }
+
     #[test]
     fn calculate_time_for_kuala_lumpur() {
         let location = Coordinates::new(3.12, 101.69);
@@ -300,4 +519,275 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn calculate_times_for_qum() {
+        // The Jafari method ignores the Hanafi madhab for Asr. These are
+        // regression values of this calculation, not the Leva timetable.
+        let coordinates = Coordinates::new(34.6401, 50.8764);
+        let params = Configuration::with(Method::Jafari, Madhab::Hanafi);
+        let irst_offset = FixedOffset::east_opt(3 * 3600 + 1800).expect("Invalid offset provided");
+        let calculate = |date| {
+            PrayerSchedule::new()
+                .on(date)
+                .for_location(coordinates)
+                .with_configuration(params)
+                .calculate_in(irst_offset)
+                .expect("Prayer times could not be calculated")
+        };
+        let format = |schedule: &ZonedPrayerTimes<FixedOffset>, prayer| {
+            schedule
+                .time(prayer)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string()
+        };
+
+        let schedule =
+            calculate(NaiveDate::from_ymd_opt(2023, 6, 21).expect("Invalid date provided"));
+
+        assert_eq!(format(&schedule, Prayer::Fajr), "3:22 AM");
+        assert_eq!(format(&schedule, Prayer::Sunrise), "4:54 AM");
+        assert_eq!(format(&schedule, Prayer::Dhuhr), "12:08 PM");
+        assert_eq!(format(&schedule, Prayer::Asr), "3:55 PM");
+        assert_eq!(format(&schedule, Prayer::Maghrib), "7:41 PM");
+        assert_eq!(format(&schedule, Prayer::Isha), "8:42 PM");
+
+        let schedule =
+            calculate(NaiveDate::from_ymd_opt(2023, 12, 21).expect("Invalid date provided"));
+
+        assert_eq!(format(&schedule, Prayer::Fajr), "5:49 AM");
+        assert_eq!(format(&schedule, Prayer::Sunrise), "7:09 AM");
+        assert_eq!(format(&schedule, Prayer::Dhuhr), "12:04 PM");
+        assert_eq!(format(&schedule, Prayer::Asr), "2:41 PM");
+        assert_eq!(format(&schedule, Prayer::Maghrib), "5:17 PM");
+        assert_eq!(format(&schedule, Prayer::Isha), "6:09 PM");
+    }
+
+    #[test]
+    fn calculate_times_for_apia() {
+        // Samoa moved to the west of the date line at the end of 2011,
+        // so its calendar is a day ahead of its mean solar time.
+        let date = NaiveDate::from_ymd_opt(2022, 6, 15).expect("Invalid date provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let wst_offset = FixedOffset::east_opt(13 * 3600).expect("Invalid offset provided");
+        let schedule = PrayerSchedule::new()
+            .on(date)
+            .for_location(Coordinates::new(-13.8333, -171.7667))
+            .with_configuration(params)
+            .calculate_in(wst_offset)
+            .expect("Prayer times could not be calculated");
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+
+        for prayer in prayers {
+            assert_eq!(schedule.time(prayer).unwrap().date_naive(), date);
+        }
+
+        for pair in prayers.windows(2) {
+            assert!(schedule.time(pair[0]).unwrap() < schedule.time(pair[1]).unwrap());
+        }
+
+        assert_eq!(
+            schedule
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "5:32 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Sunrise)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "6:48 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "12:28 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Asr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "3:42 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Maghrib)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "6:07 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "7:19 PM"
+        );
+    }
+
+    #[test]
+    fn calculate_times_for_kiritimati() {
+        // The Line Islands are at UTC+14, ten hours ahead of their mean solar time.
+        let date = NaiveDate::from_ymd_opt(2022, 6, 15).expect("Invalid date provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let lint_offset = FixedOffset::east_opt(14 * 3600).expect("Invalid offset provided");
+        let schedule = PrayerSchedule::new()
+            .on(date)
+            .for_location(Coordinates::new(1.8721, -157.4278))
+            .with_configuration(params)
+            .calculate_in(lint_offset)
+            .expect("Prayer times could not be calculated");
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ] {
+            assert_eq!(schedule.time(prayer).unwrap().date_naive(), date);
+        }
+
+        assert_eq!(
+            schedule
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "5:08 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "12:31 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "7:48 PM"
+        );
+    }
+
+    #[test]
+    fn calculate_times_for_suva() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 15).expect("Invalid date provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let fjt_offset = FixedOffset::east_opt(12 * 3600).expect("Invalid offset provided");
+        let schedule = PrayerSchedule::new()
+            .on(date)
+            .for_location(Coordinates::new(-18.1416, 178.4419))
+            .with_configuration(params)
+            .calculate_in(fjt_offset)
+            .expect("Prayer times could not be calculated");
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ] {
+            assert_eq!(schedule.time(prayer).unwrap().date_naive(), date);
+        }
+
+        assert_eq!(
+            schedule
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "5:17 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "12:08 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "6:51 PM"
+        );
+    }
+
+    #[test]
+    fn calculate_times_for_nukualofa_at_longitude() {
+        // Tonga is at UTC+13, but its nautical time zone is UTC-12.
+        let date = NaiveDate::from_ymd_opt(2022, 6, 15).expect("Invalid date provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let schedule =
+            ZonedPrayerTimes::at_longitude(date, Coordinates::new(-21.1394, -175.2046), params)
+                .expect("Prayer times could not be calculated");
+
+        assert_eq!(schedule.timezone().local_minus_utc(), -12 * 3600);
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ] {
+            assert_eq!(schedule.time(prayer).unwrap().date_naive(), date);
+        }
+
+        assert_eq!(
+            schedule
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "4:57 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "11:42 AM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "6:22 PM"
+        );
+    }
 }
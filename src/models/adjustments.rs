@@ -6,10 +6,13 @@
 
 use std::default::Default;
 
+use super::prayer::Prayer;
+
 /// Time adjustment for all prayer times.
 /// The value is specified in *minutes* and
 /// can be either positive or negative.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeAdjustment {
     pub fajr: i64,
     pub sunrise: i64,
@@ -30,6 +33,19 @@ impl TimeAdjustment {
             isha: isha,
         }
     }
+
+    /// Returns the adjustment of the prayer in minutes.
+    pub fn minutes(&self, prayer: Prayer) -> i64 {
+        match prayer {
+            Prayer::Fajr => self.fajr,
+            Prayer::Sunrise => self.sunrise,
+            Prayer::Dhuhr => self.dhuhr,
+            Prayer::Asr => self.asr,
+            Prayer::Maghrib => self.maghrib,
+            Prayer::Isha => self.isha,
+            _ => 0,
+        }
+    }
 }
 
 impl Default for TimeAdjustment {
@@ -45,6 +61,24 @@ impl Default for TimeAdjustment {
     }
 }
 
+/// The adjustments of a prayer time in minutes, by the layer
+/// they come from. The layers are applied in order, i.e. those
+/// of the method, then those of the local authority or mosque,
+/// and then those of the user.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct AdjustmentLayers {
+    pub method: i64,
+    pub authority: i64,
+    pub user: i64,
+}
+
+impl AdjustmentLayers {
+    /// The sum of the adjustments of all layers.
+    pub fn total(&self) -> i64 {
+        self.method + self.authority + self.user
+    }
+}
+
 /// Builder struct for the [TimeAdjustment](struct.TimeAdjustment.html).
 /// It is recommended to use this for all needed adjustments.
 pub struct Adjustment {
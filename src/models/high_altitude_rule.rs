@@ -4,10 +4,18 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+use std::str::FromStr;
+
 use crate::astronomy::unit::Coordinates;
+use crate::error::ParseError;
+
+use super::is_named;
 
 /// Rule for approximating Fajr and Isha at high latitudes
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum HighLatitudeRule {
     /// Fajr won't be earlier than the midpoint of the night and isha
     /// won't be later than the midpoint of the night. This is the default
@@ -38,6 +46,33 @@ impl HighLatitudeRule {
     }
 }
 
+impl fmt::Display for HighLatitudeRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HighLatitudeRule::MiddleOfTheNight => "middle_of_the_night",
+            HighLatitudeRule::SeventhOfTheNight => "seventh_of_the_night",
+            HighLatitudeRule::TwilightAngle => "twilight_angle",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for HighLatitudeRule {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<HighLatitudeRule, ParseError> {
+        [
+            HighLatitudeRule::MiddleOfTheNight,
+            HighLatitudeRule::SeventhOfTheNight,
+            HighLatitudeRule::TwilightAngle,
+        ]
+        .into_iter()
+        .find(|value| is_named(name, &value.to_string()))
+        .ok_or_else(|| ParseError::new("high latitude rule", name))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,10 +4,19 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+use super::is_named;
+
 /// Setting for the Asr prayer time.
 /// For Hanafi madhab, the Asr is bit later
 /// than that of the Shafi madhab.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Madhab {
     Shafi = 1,
     Hanafi = 2,
@@ -19,6 +28,28 @@ impl Madhab {
     }
 }
 
+impl fmt::Display for Madhab {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Madhab::Shafi => "shafi",
+            Madhab::Hanafi => "hanafi",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Madhab {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<Madhab, ParseError> {
+        [Madhab::Shafi, Madhab::Hanafi]
+            .into_iter()
+            .find(|value| is_named(name, &value.to_string()))
+            .ok_or_else(|| ParseError::new("madhab", name))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +67,14 @@ mod tests {
 
         assert_eq!(hanafi.shadow(), 2);
     }
+
+    #[test]
+    fn madhab_names() {
+        assert_eq!(Madhab::Hanafi.to_string(), "hanafi");
+        assert_eq!("Shafi".parse::<Madhab>(), Ok(Madhab::Shafi));
+        assert_eq!(
+            "maliki".parse::<Madhab>().unwrap_err().to_string(),
+            "Unknown madhab: \"maliki\""
+        );
+    }
 }
@@ -4,12 +4,26 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
 use super::adjustments::Adjustment;
+use super::high_altitude_rule::HighLatitudeRule;
+use super::is_named;
+use super::method_info::{MethodInfo, TimeDefinition, TwilightModel};
+use super::night_start::NightStart;
 use super::parameters::{Configuration, Parameters};
+use super::registry::{MethodId, MethodRegistry};
 use super::rounding::Rounding;
 
 /// Provides preset configuration for a few authorities
 /// for calculating prayer times.
+///
+/// When serialized, a method is represented by its name,
+/// e.g. `"moonsighting_committee"`, or by its id if it is a
+/// [custom method](struct.MethodRegistry.html).
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum Method {
     /// Muslim World League. Standard Fajr time with an angle of 18°.
@@ -25,9 +39,8 @@ pub enum Method {
     Karachi,
 
     /// Umm al-Qura University, Makkah. Uses a fixed interval of 90 minutes
-    /// from maghrib to calculate Isha. And a slightly earlier Fajr time with
-    /// an angle of 18.5°. Note: you should add a +30 minute custom adjustment
-    /// for Isha during Ramadan.
+    /// from maghrib to calculate Isha, or 120 minutes during Ramadan. And a
+    /// slightly earlier Fajr time with an angle of 18.5°.
     UmmAlQura,
 
     /// Used in the UAE. Slightly earlier Fajr time and slightly later Isha
@@ -49,7 +62,7 @@ pub enum Method {
     /// Standard Fajr time with an angle of 18°. Slightly earlier Isha time with an angle of 17.5°.
     Kuwait,
 
-    /// Same Isha interval as `ummAlQura` but with the standard Fajr time using an angle of 18°.
+    /// Same Isha intervals as `ummAlQura` but with the standard Fajr time using an angle of 18°.
     Qatar,
 
     /// Used in Singapore, Malaysia, and Indonesia. Early Fajr time with an angle of 20°
@@ -61,16 +74,303 @@ pub enum Method {
     /// reaching an angle of 4.5° below the horizon.
     Tehran,
 
+    /// Shia Ithna-Ashari, Leva Institute, Qum. Fajr time with an angle of 16°,
+    /// Maghrib with an angle of 4° and Isha with an angle of 14°. The night
+    /// is from sunset to Fajr, and Asr is always calculated with the shadow
+    /// length of the Shafi madhab.
+    Jafari,
+
     /// An approximation of the Diyanet method used in Turkey.
     /// This approximation is less accurate outside the region of Turkey.
     Turkey,
 
+    /// Ministry of Habous and Islamic Affairs, Morocco. Fajr time with an angle
+    /// of 19° and Isha time with an angle of 17°. Dhuhr and Maghrib are five
+    /// minutes later than the calculated times.
+    Morocco,
+
+    /// Ministry of Religious Affairs and Wakfs, Algeria. Fajr time with an angle
+    /// of 18° and Isha time with an angle of 17°. Maghrib is three minutes later
+    /// than sunset.
+    Algeria,
+
+    /// Ministry of Religious Affairs, Tunisia. Fajr and Isha times with an
+    /// angle of 18°.
+    Tunisia,
+
+    /// Union des Organisations Islamiques de France (UOIF). Late Fajr and early
+    /// Isha times with angles of 12°, with the twilight angle rule at high latitudes.
+    Uoif,
+
+    /// Spiritual Administration of Muslims of Russia. Fajr time with an angle of 16°
+    /// and Isha time with an angle of 15°, with the seventh of the night rule at
+    /// high latitudes.
+    Russia,
+
+    /// Grande Mosquée de Paris. Fajr time with an angle of 18° and Isha time with an
+    /// angle of 17°, with the seventh of the night rule at high latitudes. The local
+    /// corrections of a mosque can be set as the `authority_adjustments`.
+    ParisMosque,
+
     /// Defaults to angles of 0°, should generally be used for making a custom method
     /// and setting your own values.
     Other,
+
+    /// A method that is defined by the user and registered
+    /// with the [MethodRegistry](struct.MethodRegistry.html).
+    Custom(MethodId),
 }
 
 impl Method {
+    /// Returns all built-in methods, in the order they are declared.
+    /// The custom methods are returned by `MethodRegistry::methods()`.
+    pub fn all() -> &'static [Method] {
+        &[
+            Method::MuslimWorldLeague,
+            Method::Egyptian,
+            Method::Karachi,
+            Method::UmmAlQura,
+            Method::Dubai,
+            Method::MoonsightingCommittee,
+            Method::NorthAmerica,
+            Method::Kuwait,
+            Method::Qatar,
+            Method::Singapore,
+            Method::Tehran,
+            Method::Jafari,
+            Method::Turkey,
+            Method::Morocco,
+            Method::Algeria,
+            Method::Tunisia,
+            Method::Uoif,
+            Method::Russia,
+            Method::ParisMosque,
+            Method::Other,
+        ]
+    }
+
+    /// Returns the description of the method, which includes how
+    /// it defines the times of Fajr, Maghrib and Isha.
+    pub fn info(&self) -> MethodInfo {
+        match self {
+            Method::MuslimWorldLeague => MethodInfo {
+                name: "Muslim World League",
+                authority: "Muslim World League",
+                regions: &["Europe", "Far East", "parts of the United States"],
+                description: "Standard Fajr time with an angle of 18° and an earlier Isha time with an angle of 17°.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.0),
+            },
+            Method::Egyptian => MethodInfo {
+                name: "Egyptian General Authority of Survey",
+                authority: "Egyptian General Authority of Survey",
+                regions: &["Africa", "Syria", "Lebanon", "Malaysia"],
+                description: "Early Fajr time with an angle of 19.5° and a slightly earlier Isha time with an angle of 17.5°.",
+                fajr: TimeDefinition::Angle(19.5),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.5),
+            },
+            Method::Karachi => MethodInfo {
+                name: "University of Islamic Sciences, Karachi",
+                authority: "University of Islamic Sciences, Karachi",
+                regions: &["Pakistan", "Bangladesh", "India", "Afghanistan"],
+                description: "Standard Fajr and Isha times with angles of 18°.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(18.0),
+            },
+            Method::UmmAlQura => MethodInfo {
+                name: "Umm al-Qura University, Makkah",
+                authority: "Umm al-Qura University",
+                regions: &["Saudi Arabia"],
+                description: "Fajr time with an angle of 18.5° and Isha 90 minutes after Maghrib, or 120 minutes during Ramadan.",
+                fajr: TimeDefinition::Angle(18.5),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Interval(90),
+            },
+            Method::Dubai => MethodInfo {
+                name: "Dubai",
+                authority: "General Authority of Islamic Affairs and Endowments",
+                regions: &["United Arab Emirates"],
+                description: "Fajr and Isha times with angles of 18.2°, with 3 minute offsets for sunrise, Dhuhr, Asr, and Maghrib.",
+                fajr: TimeDefinition::Angle(18.2),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(18.2),
+            },
+            Method::MoonsightingCommittee => MethodInfo {
+                name: "Moonsighting Committee Worldwide",
+                authority: "Moonsighting Committee Worldwide",
+                regions: &["North America", "United Kingdom"],
+                description: "Fajr and Isha times with angles of 18°, limited by seasonal adjustments, and the 1/7 rule above 55° latitude.",
+                fajr: TimeDefinition::SeasonalAngle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::SeasonalAngle(18.0),
+            },
+            Method::NorthAmerica => MethodInfo {
+                name: "Islamic Society of North America",
+                authority: "Islamic Society of North America",
+                regions: &["North America"],
+                description: "Later Fajr and earlier Isha times with angles of 15°.",
+                fajr: TimeDefinition::Angle(15.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(15.0),
+            },
+            Method::Kuwait => MethodInfo {
+                name: "Kuwait",
+                authority: "Ministry of Awqaf and Islamic Affairs, Kuwait",
+                regions: &["Kuwait"],
+                description: "Standard Fajr time with an angle of 18° and a slightly earlier Isha time with an angle of 17.5°.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.5),
+            },
+            Method::Qatar => MethodInfo {
+                name: "Qatar",
+                authority: "Ministry of Awqaf and Islamic Affairs, Qatar",
+                regions: &["Qatar"],
+                description: "Standard Fajr time with an angle of 18° and Isha 90 minutes after Maghrib, or 120 minutes during Ramadan.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Interval(90),
+            },
+            Method::Singapore => MethodInfo {
+                name: "Majlis Ugama Islam Singapura",
+                authority: "Majlis Ugama Islam Singapura",
+                regions: &["Singapore", "Malaysia", "Indonesia"],
+                description: "Early Fajr time with an angle of 20° and standard Isha time with an angle of 18°.",
+                fajr: TimeDefinition::Angle(20.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(18.0),
+            },
+            Method::Tehran => MethodInfo {
+                name: "Institute of Geophysics, University of Tehran",
+                authority: "Institute of Geophysics, University of Tehran",
+                regions: &["Iran"],
+                description: "Fajr time with an angle of 17.7°, Maghrib with an angle of 4.5° and Isha with an angle of 14°.",
+                fajr: TimeDefinition::Angle(17.7),
+                maghrib: TimeDefinition::Angle(4.5),
+                isha: TimeDefinition::Angle(14.0),
+            },
+            Method::Jafari => MethodInfo {
+                name: "Shia Ithna-Ashari, Leva Institute, Qum",
+                authority: "Leva Research Institute, Qum",
+                regions: &["Iran", "Iraq", "Shia communities worldwide"],
+                description: "Fajr time with an angle of 16°, Maghrib with an angle of 4° and Isha with an angle of 14°, with the night from sunset to Fajr.",
+                fajr: TimeDefinition::Angle(16.0),
+                maghrib: TimeDefinition::Angle(4.0),
+                isha: TimeDefinition::Angle(14.0),
+            },
+            Method::Turkey => MethodInfo {
+                name: "Diyanet",
+                authority: "Presidency of Religious Affairs, Turkey",
+                regions: &["Turkey"],
+                description: "An approximation of the Diyanet method, with angles of 18° for Fajr and 17° for Isha.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.0),
+            },
+            Method::Morocco => MethodInfo {
+                name: "Ministry of Habous and Islamic Affairs, Morocco",
+                authority: "Ministry of Habous and Islamic Affairs, Morocco",
+                regions: &["Morocco"],
+                description: "Fajr time with an angle of 19° and Isha time with an angle of 17°, with Dhuhr and Maghrib five minutes later.",
+                fajr: TimeDefinition::Angle(19.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.0),
+            },
+            Method::Algeria => MethodInfo {
+                name: "Ministry of Religious Affairs and Wakfs, Algeria",
+                authority: "Ministry of Religious Affairs and Wakfs, Algeria",
+                regions: &["Algeria"],
+                description: "Fajr time with an angle of 18° and Isha time with an angle of 17°, with Maghrib three minutes after sunset.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.0),
+            },
+            Method::Tunisia => MethodInfo {
+                name: "Ministry of Religious Affairs, Tunisia",
+                authority: "Ministry of Religious Affairs, Tunisia",
+                regions: &["Tunisia"],
+                description: "Fajr and Isha times with an angle of 18°.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(18.0),
+            },
+            Method::Uoif => MethodInfo {
+                name: "Union des Organisations Islamiques de France",
+                authority: "Union des Organisations Islamiques de France",
+                regions: &["France"],
+                description: "Late Fajr and early Isha times with angles of 12°.",
+                fajr: TimeDefinition::Angle(12.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(12.0),
+            },
+            Method::Russia => MethodInfo {
+                name: "Spiritual Administration of Muslims of Russia",
+                authority: "Spiritual Administration of Muslims of Russia",
+                regions: &["Russia"],
+                description: "Fajr time with an angle of 16° and Isha time with an angle of 15°.",
+                fajr: TimeDefinition::Angle(16.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(15.0),
+            },
+            Method::ParisMosque => MethodInfo {
+                name: "Grande Mosquée de Paris",
+                authority: "Grande Mosquée de Paris",
+                regions: &["France"],
+                description: "Fajr time with an angle of 18° and Isha time with an angle of 17°.",
+                fajr: TimeDefinition::Angle(18.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(17.0),
+            },
+            Method::Other => MethodInfo {
+                name: "Other",
+                authority: "None",
+                regions: &[],
+                description: "Angles of 0°, for making a custom method.",
+                fajr: TimeDefinition::Angle(0.0),
+                maghrib: TimeDefinition::Sunset,
+                isha: TimeDefinition::Angle(0.0),
+            },
+
+            Method::Custom(id) => {
+                let params = self.parameters();
+                let twilight = |angle| match self.twilight() {
+                    TwilightModel::Standard => TimeDefinition::Angle(angle),
+                    TwilightModel::Seasonal => TimeDefinition::SeasonalAngle(angle),
+                };
+
+                MethodInfo {
+                    name: id.name(),
+                    authority: "User-defined",
+                    regions: &[],
+                    description: "A method that is defined by the user.",
+                    fajr: twilight(params.fajr_angle),
+                    maghrib: if params.maghrib_angle > 0.0 {
+                        TimeDefinition::Angle(params.maghrib_angle)
+                    } else {
+                        TimeDefinition::Sunset
+                    },
+                    isha: if params.isha_interval > 0 {
+                        TimeDefinition::Interval(params.isha_interval)
+                    } else {
+                        twilight(params.isha_angle)
+                    },
+                }
+            }
+        }
+    }
+
+    /// How the method determines the twilight times of Fajr and Isha.
+    pub fn twilight(&self) -> TwilightModel {
+        match self {
+            Method::MoonsightingCommittee => TwilightModel::Seasonal,
+            Method::Custom(id) => id.twilight(),
+            _ => TwilightModel::Standard,
+        }
+    }
+
     pub fn parameters(&self) -> Parameters {
         match self {
             Method::MuslimWorldLeague => Configuration::new(18.0, 17.0)
@@ -91,6 +391,7 @@ impl Method {
             Method::UmmAlQura => Configuration::new(18.5, 0.0)
                 .method(*self)
                 .isha_interval(90)
+                .ramadan_isha_interval(120)
                 .done(),
             Method::Dubai => Configuration::new(18.2, 18.2)
                 .method(*self)
@@ -119,6 +420,7 @@ impl Method {
             Method::Qatar => Configuration::new(18.0, 0.0)
                 .method(*self)
                 .isha_interval(90)
+                .ramadan_isha_interval(120)
                 .done(),
 
             Method::Singapore => Configuration::new(20.0, 18.0)
@@ -132,6 +434,13 @@ impl Method {
                 .maghrib_angle(4.5)
                 .done(),
 
+            Method::Jafari => Configuration::new(16.0, 14.0)
+                .method(*self)
+                .maghrib_angle(4.0)
+                .night_start(NightStart::Sunset)
+                .high_latitude_rule(HighLatitudeRule::TwilightAngle)
+                .done(),
+
             Method::Turkey => Configuration::new(18.0, 17.0)
                 .method(*self)
                 .method_adjustments(
@@ -144,14 +453,102 @@ impl Method {
                 )
                 .done(),
 
+            Method::Morocco => Configuration::new(19.0, 17.0)
+                .method(*self)
+                .method_adjustments(Adjustment::new().dhuhr(5).maghrib(5).done())
+                .done(),
+
+            Method::Algeria => Configuration::new(18.0, 17.0)
+                .method(*self)
+                .method_adjustments(Adjustment::new().maghrib(3).done())
+                .done(),
+
+            Method::Tunisia => Configuration::new(18.0, 18.0).method(*self).done(),
+
+            Method::Uoif => Configuration::new(12.0, 12.0)
+                .method(*self)
+                .high_latitude_rule(HighLatitudeRule::TwilightAngle)
+                .done(),
+
+            Method::Russia => Configuration::new(16.0, 15.0)
+                .method(*self)
+                .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+                .done(),
+
+            Method::ParisMosque => Configuration::new(18.0, 17.0)
+                .method(*self)
+                .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+                .done(),
+
             Method::Other => Configuration::new(0.0, 0.0).method(*self).done(),
+
+            Method::Custom(id) => Configuration::from(id.parameters()).method(*self).done(),
         }
     }
 }
 
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Method::MuslimWorldLeague => "muslim_world_league",
+            Method::Egyptian => "egyptian",
+            Method::Karachi => "karachi",
+            Method::UmmAlQura => "umm_al_qura",
+            Method::Dubai => "dubai",
+            Method::MoonsightingCommittee => "moonsighting_committee",
+            Method::NorthAmerica => "north_america",
+            Method::Kuwait => "kuwait",
+            Method::Qatar => "qatar",
+            Method::Singapore => "singapore",
+            Method::Tehran => "tehran",
+            Method::Jafari => "jafari",
+            Method::Turkey => "turkey",
+            Method::Morocco => "morocco",
+            Method::Algeria => "algeria",
+            Method::Tunisia => "tunisia",
+            Method::Uoif => "uoif",
+            Method::Russia => "russia",
+            Method::ParisMosque => "paris_mosque",
+            Method::Other => "other",
+            Method::Custom(id) => id.as_str(),
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Method {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<Method, ParseError> {
+        Method::all()
+            .iter()
+            .copied()
+            .find(|value| is_named(name, &value.to_string()))
+            .or_else(|| MethodRegistry::get(name))
+            .ok_or_else(|| ParseError::new("method", name))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Method {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Method {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Method, D::Error> {
+        let name = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::prayer::Prayer;
 
     #[test]
     fn parameters_for_muslim_world_league() {
@@ -263,6 +660,61 @@ mod tests {
         assert_eq!(params.isha_interval, 0);
     }
 
+    #[test]
+    fn parameters_for_maghreb() {
+        let params = Method::Morocco.parameters();
+
+        assert_eq!(params.method, Method::Morocco);
+        assert_eq!(params.fajr_angle, 19.0);
+        assert_eq!(params.isha_angle, 17.0);
+        assert_eq!(params.time_adjustments(Prayer::Dhuhr), 5);
+        assert_eq!(params.time_adjustments(Prayer::Maghrib), 5);
+
+        let params = Method::Algeria.parameters();
+
+        assert_eq!(params.method, Method::Algeria);
+        assert_eq!(params.fajr_angle, 18.0);
+        assert_eq!(params.isha_angle, 17.0);
+        assert_eq!(params.time_adjustments(Prayer::Maghrib), 3);
+
+        let params = Method::Tunisia.parameters();
+
+        assert_eq!(params.method, Method::Tunisia);
+        assert_eq!(params.fajr_angle, 18.0);
+        assert_eq!(params.isha_angle, 18.0);
+        assert_eq!(params.time_adjustments(Prayer::Maghrib), 0);
+    }
+
+    #[test]
+    fn parameters_for_europe() {
+        let params = Method::Uoif.parameters();
+
+        assert_eq!(params.method, Method::Uoif);
+        assert_eq!(params.fajr_angle, 12.0);
+        assert_eq!(params.isha_angle, 12.0);
+        assert_eq!(params.high_latitude_rule, HighLatitudeRule::TwilightAngle);
+
+        let params = Method::Russia.parameters();
+
+        assert_eq!(params.method, Method::Russia);
+        assert_eq!(params.fajr_angle, 16.0);
+        assert_eq!(params.isha_angle, 15.0);
+        assert_eq!(
+            params.high_latitude_rule,
+            HighLatitudeRule::SeventhOfTheNight
+        );
+
+        let params = Method::ParisMosque.parameters();
+
+        assert_eq!(params.method, Method::ParisMosque);
+        assert_eq!(params.fajr_angle, 18.0);
+        assert_eq!(params.isha_angle, 17.0);
+        assert_eq!(
+            params.high_latitude_rule,
+            HighLatitudeRule::SeventhOfTheNight
+        );
+    }
+
     #[test]
     fn parameters_for_other() {
         let method = Method::Other;
@@ -273,4 +725,111 @@ mod tests {
         assert_eq!(params.isha_angle, 0.0);
         assert_eq!(params.isha_interval, 0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_method_names() {
+        assert_eq!(
+            serde_json::to_string(&Method::MoonsightingCommittee).unwrap(),
+            "\"moonsighting_committee\""
+        );
+        assert_eq!(
+            serde_json::to_string(&Method::UmmAlQura).unwrap(),
+            "\"umm_al_qura\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Method>("\"north_america\"").unwrap(),
+            Method::NorthAmerica
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        assert_eq!(
+            Method::MoonsightingCommittee.to_string(),
+            "moonsighting_committee"
+        );
+        assert_eq!(
+            "moonsighting_committee".parse::<Method>(),
+            Ok(Method::MoonsightingCommittee)
+        );
+        assert_eq!(
+            "MoonsightingCommittee".parse::<Method>(),
+            Ok(Method::MoonsightingCommittee)
+        );
+        assert_eq!("umm-al-qura".parse::<Method>(), Ok(Method::UmmAlQura));
+        assert_eq!(
+            "moonsighting".parse::<Method>(),
+            Err(ParseError::new("method", "moonsighting"))
+        );
+    }
+
+    #[test]
+    fn all_methods() {
+        let methods = Method::all();
+
+        assert_eq!(methods.len(), 20);
+        assert_eq!(methods.first(), Some(&Method::MuslimWorldLeague));
+        assert_eq!(methods.last(), Some(&Method::Other));
+
+        for method in methods {
+            assert_eq!(method.to_string().parse::<Method>(), Ok(*method));
+        }
+    }
+
+    #[test]
+    fn method_info() {
+        let info = Method::NorthAmerica.info();
+
+        assert_eq!(info.name, "Islamic Society of North America");
+        assert_eq!(info.regions, &["North America"]);
+        assert_eq!(info.fajr, TimeDefinition::Angle(15.0));
+        assert_eq!(info.isha, TimeDefinition::Angle(15.0));
+    }
+
+    #[test]
+    fn method_info_matches_parameters() {
+        for method in Method::all() {
+            let info = method.info();
+            let params = method.parameters();
+
+            match info.fajr {
+                TimeDefinition::Angle(angle) | TimeDefinition::SeasonalAngle(angle) => {
+                    assert_eq!(angle, params.fajr_angle, "Fajr of {:?}", method)
+                }
+                _ => panic!("Fajr of {:?} is not defined by an angle", method),
+            }
+
+            match info.maghrib {
+                TimeDefinition::Sunset => {
+                    assert_eq!(params.maghrib_angle, 0.0, "Maghrib of {:?}", method)
+                }
+                TimeDefinition::Angle(angle) => {
+                    assert_eq!(angle, params.maghrib_angle, "Maghrib of {:?}", method)
+                }
+                _ => panic!("Maghrib of {:?} has an unknown definition", method),
+            }
+
+            match info.isha {
+                TimeDefinition::Angle(angle) | TimeDefinition::SeasonalAngle(angle) => {
+                    assert_eq!(angle, params.isha_angle, "Isha of {:?}", method);
+                    assert_eq!(params.isha_interval, 0, "Isha of {:?}", method);
+                }
+                TimeDefinition::Interval(interval) => {
+                    assert_eq!(interval, params.isha_interval, "Isha of {:?}", method)
+                }
+                TimeDefinition::Sunset => panic!("Isha of {:?} is at sunset", method),
+            }
+
+            let seasonal = matches!(info.fajr, TimeDefinition::SeasonalAngle(_))
+                || matches!(info.isha, TimeDefinition::SeasonalAngle(_));
+
+            assert_eq!(
+                seasonal,
+                params.method == Method::MoonsightingCommittee,
+                "Seasonal adjustment of {:?}",
+                method
+            );
+        }
+    }
 }
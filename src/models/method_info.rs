@@ -0,0 +1,59 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// How the twilight times of Fajr and Isha are determined
+/// by a calculation method.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TwilightModel {
+    /// By the angles of the sun, along with the high latitude rule.
+    Standard,
+
+    /// By the angles of the sun, but no further from sunrise or sunset
+    /// than the seasonal adjustments of the Moonsighting Committee, and
+    /// by the 1/7 rule above 55° latitude.
+    Seasonal,
+}
+
+/// How the time of a prayer is defined by a calculation method.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum TimeDefinition {
+    /// When the sun reaches the angle below the horizon.
+    Angle(f64),
+
+    /// When the sun reaches the angle below the horizon, but no further
+    /// from sunrise or sunset than the seasonal adjustment of the
+    /// Moonsighting Committee.
+    SeasonalAngle(f64),
+
+    /// A fixed number of minutes after Maghrib.
+    Interval(i32),
+
+    /// At sunset.
+    Sunset,
+}
+
+/// A description of a calculation method, e.g. for
+/// listing the methods in a settings screen.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct MethodInfo {
+    /// The name of the method, in English.
+    pub name: &'static str,
+
+    /// The authority that issued the method.
+    pub authority: &'static str,
+
+    /// The regions where the method is commonly used.
+    pub regions: &'static [&'static str],
+
+    /// A short description of the method.
+    pub description: &'static str,
+
+    pub fajr: TimeDefinition,
+    pub maghrib: TimeDefinition,
+    pub isha: TimeDefinition,
+}
@@ -8,7 +8,27 @@ pub mod adjustments;
 pub mod high_altitude_rule;
 pub mod madhab;
 pub mod method;
+pub mod method_info;
+pub mod night_start;
 pub mod parameters;
+pub mod polar_resolution;
 pub mod prayer;
+pub mod registry;
 pub mod rounding;
 pub mod shafaq;
+pub mod status;
+pub mod validation;
+
+// Whether the name is the given name of a setting, where the
+// case and the separators between the words are ignored, e.g.
+// "MoonsightingCommittee" or "moonsighting-committee".
+pub(crate) fn is_named(name: &str, expected: &str) -> bool {
+    let normalized = |name: &str| {
+        name.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+
+    normalized(name) == normalized(expected)
+}
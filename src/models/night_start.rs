@@ -0,0 +1,63 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+use super::is_named;
+
+/// The start of the night, from which the middle of the night
+/// and the last third of the night are measured until Fajr.
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NightStart {
+    /// The night starts at Maghrib. This is the default value.
+    #[default]
+    Maghrib,
+
+    /// The night starts at sunset, as in the Jafari method.
+    Sunset,
+}
+
+impl fmt::Display for NightStart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            NightStart::Maghrib => "maghrib",
+            NightStart::Sunset => "sunset",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for NightStart {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<NightStart, ParseError> {
+        [NightStart::Maghrib, NightStart::Sunset]
+            .into_iter()
+            .find(|value| is_named(name, &value.to_string()))
+            .ok_or_else(|| ParseError::new("night start", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parameters::Configuration;
+
+    #[test]
+    fn default_night_start() {
+        let params = Configuration::new(18.0, 17.0).done();
+
+        assert_eq!(params.night_start, NightStart::Maghrib);
+        assert_eq!("Sunset".parse::<NightStart>(), Ok(NightStart::Sunset));
+        assert_eq!(NightStart::Sunset.to_string(), "sunset");
+    }
+}
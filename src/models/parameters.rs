@@ -4,13 +4,27 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
-use super::adjustments::TimeAdjustment;
+use chrono::NaiveDate;
+
+use crate::hijri::month::HijriMonth;
+use crate::hijri::{HijriCalendar, HijriDate};
+
+use super::adjustments::{AdjustmentLayers, TimeAdjustment};
 use super::high_altitude_rule::HighLatitudeRule;
 use super::madhab::Madhab;
 use super::method::Method;
+use super::night_start::NightStart;
+use super::polar_resolution::PolarResolution;
 use super::prayer::Prayer;
 use super::rounding::Rounding;
 use super::shafaq::Shafaq;
+use super::validation::ParameterIssue;
+
+// The adjustments of a prayer, in minutes, above which
+// they are reported as an issue by the validation.
+const LARGE_ADJUSTMENT: i64 = 60;
+const MAX_ADJUSTMENT: i64 = 3 * 60;
+const MINUTES_PER_DAY: i32 = 24 * 60;
 
 /// Settings that are used for determining the
 /// the correct prayer time.
@@ -18,16 +32,29 @@ use super::shafaq::Shafaq;
 /// It is recommended to use [Configuration](struct.Configuration.html) to build
 /// the parameters that are need.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameters {
     pub method: Method,
     pub fajr_angle: f64,
     pub maghrib_angle: f64,
     pub isha_angle: f64,
     pub isha_interval: i32,
+
+    /// The interval of Isha after Maghrib during Ramadan, on the
+    /// Umm al-Qura calendar, or zero to use `isha_interval`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ramadan_isha_interval: i32,
     pub madhab: Madhab,
     pub high_latitude_rule: HighLatitudeRule,
+    pub polar_resolution: PolarResolution,
+
+    /// The start of the night for the middle of the night and Qiyam.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub night_start: NightStart,
     pub adjustments: TimeAdjustment,
     pub method_adjustments: TimeAdjustment,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub authority_adjustments: TimeAdjustment,
     pub rounding: Rounding,
     pub shafaq: Shafaq,
 }
@@ -40,15 +67,50 @@ impl Parameters {
             isha_angle: isha_angle,
             method: Method::Other,
             isha_interval: 0,
+            ramadan_isha_interval: 0,
             madhab: Madhab::Shafi,
             high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            polar_resolution: PolarResolution::Unresolved,
+            night_start: NightStart::Maghrib,
             adjustments: TimeAdjustment::default(),
             method_adjustments: TimeAdjustment::default(),
+            authority_adjustments: TimeAdjustment::default(),
             rounding: Rounding::Nearest,
             shafaq: Shafaq::General,
         }
     }
 
+    /// Returns the interval of Isha after Maghrib on the date, which
+    /// is the `ramadan_isha_interval` when it is set and the night
+    /// after the date is in Ramadan on the Umm al-Qura calendar. The
+    /// Hijri day starts at Maghrib, so the night belongs to the Hijri
+    /// date of the following day, e.g. the night before the first
+    /// day of Ramadan is its first night and the night before Eid
+    /// is not in Ramadan.
+    pub fn isha_interval_on(&self, date: NaiveDate) -> i32 {
+        let night = date.succ_opt().unwrap_or(date);
+        let hijri = HijriDate::from_gregorian(night, HijriCalendar::UmmAlQura);
+
+        if self.ramadan_isha_interval > 0 && hijri.month == HijriMonth::Ramadan {
+            self.ramadan_isha_interval
+        } else {
+            self.isha_interval
+        }
+    }
+
+    /// Returns the shadow length for Asr, which is the one of the madhab,
+    /// except for the Jafari method that always uses the shadow length
+    /// of the Shafi madhab.
+    pub(crate) fn asr_shadow(&self) -> f64 {
+        let madhab = if self.method == Method::Jafari {
+            Madhab::Shafi
+        } else {
+            self.madhab
+        };
+
+        madhab.shadow().into()
+    }
+
     pub fn night_portions(&self) -> (f64, f64) {
         match self.high_latitude_rule {
             HighLatitudeRule::MiddleOfTheNight => (1.0 / 2.0, 1.0 / 2.0),
@@ -57,17 +119,85 @@ impl Parameters {
         }
     }
 
+    /// Returns the adjustment of the prayer in minutes, which
+    /// is the sum of the adjustments of all layers.
     pub fn time_adjustments(&self, prayer: Prayer) -> i64 {
-        match prayer {
-            Prayer::Fajr => self.adjustments.fajr + self.method_adjustments.fajr,
-            Prayer::Sunrise => self.adjustments.sunrise + self.method_adjustments.sunrise,
-            Prayer::Dhuhr => self.adjustments.dhuhr + self.method_adjustments.dhuhr,
-            Prayer::Asr => self.adjustments.asr + self.method_adjustments.asr,
-            Prayer::Maghrib => self.adjustments.maghrib + self.method_adjustments.maghrib,
-            Prayer::Isha => self.adjustments.isha + self.method_adjustments.isha,
-            _ => 0,
+        self.adjustment_layers(prayer).total()
+    }
+
+    /// Returns the adjustments of the prayer by the layer they
+    /// come from, i.e. the method, the authority and the user.
+    pub fn adjustment_layers(&self, prayer: Prayer) -> AdjustmentLayers {
+        AdjustmentLayers {
+            method: self.method_adjustments.minutes(prayer),
+            authority: self.authority_adjustments.minutes(prayer),
+            user: self.adjustments.minutes(prayer),
         }
     }
+
+    /// Checks the parameters for values that are out of range, or that
+    /// are inconsistent with each other. The issues with a severity of
+    /// `Error` keep the [PrayerSchedule](struct.PrayerSchedule.html)
+    /// from calculating the prayer times.
+    pub fn validate(&self) -> Vec<ParameterIssue> {
+        let mut issues = Vec::new();
+        let angles = [
+            (Prayer::Fajr, self.fajr_angle),
+            (Prayer::Maghrib, self.maghrib_angle),
+            (Prayer::Isha, self.isha_angle),
+        ];
+
+        for (prayer, angle) in angles {
+            if !(0.0..90.0).contains(&angle) {
+                issues.push(ParameterIssue::AngleOutOfRange { prayer, angle });
+            }
+        }
+
+        let missing_angle = if self.high_latitude_rule == HighLatitudeRule::TwilightAngle {
+            ParameterIssue::TwilightAngleWithoutAngle
+        } else {
+            ParameterIssue::MissingAngle
+        };
+
+        if self.fajr_angle == 0.0 {
+            issues.push(missing_angle(Prayer::Fajr));
+        }
+
+        if self.isha_interval == 0 && self.isha_angle == 0.0 {
+            issues.push(missing_angle(Prayer::Isha));
+        } else if self.isha_interval > 0 && self.isha_angle > 0.0 {
+            issues.push(ParameterIssue::IshaAngleAndInterval);
+        } else {
+            // Nothing to do.
+        }
+
+        for interval in [self.isha_interval, self.ramadan_isha_interval] {
+            if !(0..MINUTES_PER_DAY).contains(&interval) {
+                issues.push(ParameterIssue::IshaIntervalOutOfRange(interval));
+            }
+        }
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ] {
+            let minutes = self.time_adjustments(prayer);
+
+            if minutes.abs() > MAX_ADJUSTMENT {
+                issues.push(ParameterIssue::AdjustmentOutOfRange { prayer, minutes });
+            } else if minutes.abs() > LARGE_ADJUSTMENT {
+                issues.push(ParameterIssue::LargeAdjustment { prayer, minutes });
+            } else {
+                // Nothing to do.
+            }
+        }
+
+        issues
+    }
 }
 
 /// A builder for the the [Parameters](struct.Parameters.html).
@@ -80,10 +210,14 @@ pub struct Configuration {
     maghrib_angle: f64,
     isha_angle: f64,
     isha_interval: i32,
+    ramadan_isha_interval: i32,
     madhab: Madhab,
     high_latitude_rule: HighLatitudeRule,
+    polar_resolution: PolarResolution,
+    night_start: NightStart,
     adjustments: TimeAdjustment,
     method_adjustments: TimeAdjustment,
+    authority_adjustments: TimeAdjustment,
     rounding: Rounding,
     shafaq: Shafaq,
 }
@@ -96,15 +230,22 @@ impl Configuration {
             isha_angle: isha_angle,
             method: Method::Other,
             isha_interval: 0,
+            ramadan_isha_interval: 0,
             madhab: Madhab::Shafi,
             high_latitude_rule: HighLatitudeRule::MiddleOfTheNight,
+            polar_resolution: PolarResolution::Unresolved,
+            night_start: NightStart::Maghrib,
             adjustments: TimeAdjustment::default(),
             method_adjustments: TimeAdjustment::default(),
+            authority_adjustments: TimeAdjustment::default(),
             rounding: Rounding::Nearest,
             shafaq: Shafaq::General,
         }
     }
 
+    /// Returns the parameters of the method with the madhab for Asr.
+    /// The other settings, e.g. the high latitude rule, are the ones
+    /// the method recommends.
     pub fn with(method: Method, madhab: Madhab) -> Parameters {
         let mut params = method.parameters();
         params.madhab = madhab;
@@ -112,6 +253,20 @@ impl Configuration {
         params
     }
 
+    pub fn fajr_angle(&mut self, angle: f64) -> &mut Configuration {
+        self.fajr_angle = angle;
+        self
+    }
+
+    /// Sets the angle for Isha, which replaces the intervals
+    /// after Maghrib if there were any.
+    pub fn isha_angle(&mut self, angle: f64) -> &mut Configuration {
+        self.isha_angle = angle;
+        self.isha_interval = 0;
+        self.ramadan_isha_interval = 0;
+        self
+    }
+
     pub fn method<'a>(&'a mut self, method: Method) -> &'a mut Configuration {
         self.method = method;
         self
@@ -125,6 +280,20 @@ impl Configuration {
         self
     }
 
+    /// Sets the adjustments of the local authority or the mosque,
+    /// which are applied after the ones of the method.
+    pub fn authority_adjustments(&mut self, adjustments: TimeAdjustment) -> &mut Configuration {
+        self.authority_adjustments = adjustments;
+        self
+    }
+
+    /// Sets the adjustments of the user, which are applied
+    /// after the ones of the method and the authority.
+    pub fn adjustments(&mut self, adjustments: TimeAdjustment) -> &mut Configuration {
+        self.adjustments = adjustments;
+        self
+    }
+
     pub fn high_latitude_rule<'a>(
         &'a mut self,
         high_latitude_rule: HighLatitudeRule,
@@ -133,6 +302,18 @@ impl Configuration {
         self
     }
 
+    pub fn polar_resolution(&mut self, polar_resolution: PolarResolution) -> &mut Configuration {
+        self.polar_resolution = polar_resolution;
+        self
+    }
+
+    /// Sets the start of the night for the middle of
+    /// the night and Qiyam.
+    pub fn night_start(&mut self, night_start: NightStart) -> &mut Configuration {
+        self.night_start = night_start;
+        self
+    }
+
     pub fn madhab<'a>(&'a mut self, madhab: Madhab) -> &'a mut Configuration {
         self.madhab = madhab;
         self
@@ -144,6 +325,13 @@ impl Configuration {
         self
     }
 
+    /// Sets the interval of Isha after Maghrib during Ramadan,
+    /// which replaces the `isha_interval` in that month.
+    pub fn ramadan_isha_interval(&mut self, interval: i32) -> &mut Configuration {
+        self.ramadan_isha_interval = interval;
+        self
+    }
+
     pub fn maghrib_angle<'a>(&'a mut self, angle: f64) -> &'a mut Configuration {
         self.maghrib_angle = angle;
         self
@@ -166,19 +354,49 @@ impl Configuration {
             isha_angle: self.isha_angle,
             method: self.method,
             isha_interval: self.isha_interval,
+            ramadan_isha_interval: self.ramadan_isha_interval,
             madhab: self.madhab,
             high_latitude_rule: self.high_latitude_rule,
+            polar_resolution: self.polar_resolution,
+            night_start: self.night_start,
             adjustments: self.adjustments,
             method_adjustments: self.method_adjustments,
+            authority_adjustments: self.authority_adjustments,
             rounding: self.rounding,
             shafaq: self.shafaq,
         }
     }
 }
 
+impl From<Parameters> for Configuration {
+    /// Starts the builder from existing parameters,
+    /// e.g. the parameters of a method.
+    fn from(params: Parameters) -> Configuration {
+        Configuration {
+            fajr_angle: params.fajr_angle,
+            maghrib_angle: params.maghrib_angle,
+            isha_angle: params.isha_angle,
+            method: params.method,
+            isha_interval: params.isha_interval,
+            ramadan_isha_interval: params.ramadan_isha_interval,
+            madhab: params.madhab,
+            high_latitude_rule: params.high_latitude_rule,
+            polar_resolution: params.polar_resolution,
+            night_start: params.night_start,
+            adjustments: params.adjustments,
+            method_adjustments: params.method_adjustments,
+            authority_adjustments: params.authority_adjustments,
+            rounding: params.rounding,
+            shafaq: params.shafaq,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::adjustments::Adjustment;
+    use crate::models::validation::Severity;
 
     #[test]
     fn calculate_parameters_with_fajr_and_isha_angles() {
@@ -227,4 +445,186 @@ mod tests {
         assert_eq!(params.isha_interval, 0);
         assert_eq!(params.madhab, Madhab::Hanafi);
     }
+
+    #[test]
+    fn parameters_using_method_keep_high_latitude_rule() {
+        let params = Configuration::with(Method::Russia, Madhab::Hanafi);
+
+        assert_eq!(
+            params.high_latitude_rule,
+            HighLatitudeRule::SeventhOfTheNight
+        );
+        assert_eq!(params.madhab, Madhab::Hanafi);
+
+        let params = Configuration::with(Method::Uoif, Madhab::Shafi);
+
+        assert_eq!(params.high_latitude_rule, HighLatitudeRule::TwilightAngle);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_parameters_round_trip() {
+        let params = Configuration::new(18.0, 17.0)
+            .method(Method::MoonsightingCommittee)
+            .madhab(Madhab::Hanafi)
+            .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .shafaq(Shafaq::Ahmer)
+            .rounding(Rounding::Up)
+            .done();
+        let json = serde_json::to_value(params).unwrap();
+
+        assert_eq!(json["method"], "moonsighting_committee");
+        assert_eq!(json["madhab"], "hanafi");
+        assert_eq!(json["high_latitude_rule"], "seventh_of_the_night");
+        assert_eq!(json["shafaq"], "ahmer");
+        assert_eq!(json["rounding"], "up");
+        assert_eq!(json["adjustments"]["fajr"], 0);
+        assert_eq!(serde_json::from_value::<Parameters>(json).unwrap(), params);
+    }
+
+    #[test]
+    fn configuration_from_method_parameters() {
+        let params = Configuration::from(Method::UmmAlQura.parameters())
+            .fajr_angle(18.0)
+            .isha_angle(17.0)
+            .done();
+
+        assert_eq!(params.method, Method::UmmAlQura);
+        assert_eq!(params.fajr_angle, 18.0);
+        assert_eq!(params.isha_angle, 17.0);
+        assert_eq!(params.isha_interval, 0);
+        assert_eq!(params.ramadan_isha_interval, 0);
+
+        let params = Configuration::from(params).isha_interval(90).done();
+
+        assert_eq!(params.isha_angle, 0.0);
+        assert_eq!(params.isha_interval, 90);
+    }
+
+    #[test]
+    fn isha_interval_during_ramadan() {
+        let params = Method::UmmAlQura.parameters();
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        // Ramadan 1444 is from 23 March to 20 April 2023.
+        assert_eq!(params.ramadan_isha_interval, 120);
+        assert_eq!(params.isha_interval_on(date(2023, 3, 21)), 90);
+        assert_eq!(params.isha_interval_on(date(2023, 3, 22)), 120);
+        assert_eq!(params.isha_interval_on(date(2023, 4, 19)), 120);
+        assert_eq!(params.isha_interval_on(date(2023, 4, 20)), 90);
+
+        let params = Configuration::new(18.0, 0.0).isha_interval(90).done();
+
+        assert_eq!(params.isha_interval_on(date(2023, 3, 23)), 90);
+    }
+
+    #[test]
+    fn configuration_from_parameters_is_unchanged() {
+        let params = Configuration::with(Method::Singapore, Madhab::Hanafi);
+
+        assert_eq!(Configuration::from(params).done(), params);
+    }
+
+    #[test]
+    fn time_adjustments_by_layer() {
+        let params = Configuration::from(Method::Dubai.parameters())
+            .authority_adjustments(Adjustment::new().maghrib(2).isha(5).done())
+            .adjustments(Adjustment::new().maghrib(1).done())
+            .done();
+
+        assert_eq!(
+            params.adjustment_layers(Prayer::Maghrib),
+            AdjustmentLayers {
+                method: 3,
+                authority: 2,
+                user: 1
+            }
+        );
+        assert_eq!(params.time_adjustments(Prayer::Maghrib), 6);
+        assert_eq!(params.time_adjustments(Prayer::Isha), 5);
+        assert_eq!(params.time_adjustments(Prayer::Fajr), 0);
+    }
+
+    #[test]
+    fn validate_method_parameters() {
+        for method in Method::all() {
+            if *method != Method::Other {
+                assert_eq!(method.parameters().validate(), vec![]);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_parameters_out_of_range() {
+        let mut params = Configuration::new(-18.0, 17.0)
+            .adjustments(Adjustment::new().dhuhr(240).done())
+            .done();
+        params.isha_interval = -30;
+        params.ramadan_isha_interval = 2000;
+
+        let issues = params.validate();
+
+        assert_eq!(
+            issues,
+            vec![
+                ParameterIssue::AngleOutOfRange {
+                    prayer: Prayer::Fajr,
+                    angle: -18.0
+                },
+                ParameterIssue::IshaIntervalOutOfRange(-30),
+                ParameterIssue::IshaIntervalOutOfRange(2000),
+                ParameterIssue::AdjustmentOutOfRange {
+                    prayer: Prayer::Dhuhr,
+                    minutes: 240
+                },
+            ]
+        );
+        assert!(issues.iter().all(|issue| issue.is_error()));
+    }
+
+    #[test]
+    fn validate_parameters_without_angles() {
+        assert_eq!(
+            Method::Other.parameters().validate(),
+            vec![
+                ParameterIssue::MissingAngle(Prayer::Fajr),
+                ParameterIssue::MissingAngle(Prayer::Isha)
+            ]
+        );
+
+        let params = Configuration::new(18.0, 0.0)
+            .high_latitude_rule(HighLatitudeRule::TwilightAngle)
+            .done();
+
+        assert_eq!(
+            params.validate(),
+            vec![ParameterIssue::TwilightAngleWithoutAngle(Prayer::Isha)]
+        );
+    }
+
+    #[test]
+    fn validate_parameters_with_warnings() {
+        let mut params = Configuration::from(Method::UmmAlQura.parameters())
+            .adjustments(Adjustment::new().maghrib(90).done())
+            .done();
+        params.isha_angle = 17.0;
+
+        let issues = params.validate();
+
+        assert_eq!(
+            issues,
+            vec![
+                ParameterIssue::IshaAngleAndInterval,
+                ParameterIssue::LargeAdjustment {
+                    prayer: Prayer::Maghrib,
+                    minutes: 90
+                },
+            ]
+        );
+        assert!(
+            issues
+                .iter()
+                .all(|issue| issue.severity() == Severity::Warning)
+        );
+    }
 }
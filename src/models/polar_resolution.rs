@@ -0,0 +1,71 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+use super::is_named;
+
+/// Rule for resolving the prayer times on days when the sun
+/// does not rise or set, e.g. inside the polar circles.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PolarResolution {
+    /// Use the times of the nearest day on which the sun both
+    /// rises and sets at the location (_aqrab al-ayyam_).
+    AqrabYaum,
+
+    /// Use the times of the nearest latitude, closer to the equator,
+    /// at which the sun both rises and sets on the same day (_aqrab al-bilad_).
+    AqrabBalad,
+
+    /// Do not resolve the times. Prayers that depend on the sun rising
+    /// or setting are reported as undefined. This is the default value.
+    Unresolved,
+}
+
+impl fmt::Display for PolarResolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PolarResolution::AqrabYaum => "aqrab_yaum",
+            PolarResolution::AqrabBalad => "aqrab_balad",
+            PolarResolution::Unresolved => "unresolved",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for PolarResolution {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<PolarResolution, ParseError> {
+        [
+            PolarResolution::AqrabYaum,
+            PolarResolution::AqrabBalad,
+            PolarResolution::Unresolved,
+        ]
+        .into_iter()
+        .find(|value| is_named(name, &value.to_string()))
+        .ok_or_else(|| ParseError::new("polar resolution", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parameters::Configuration;
+
+    #[test]
+    fn default_polar_resolution() {
+        let params = Configuration::new(18.0, 17.0).done();
+
+        assert_eq!(params.polar_resolution, PolarResolution::Unresolved);
+    }
+}
@@ -9,6 +9,8 @@ use chrono::{Datelike, Utc, Weekday};
 /// Names of all obligatory prayers,
 /// sunrise, and Qiyam.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Prayer {
     Fajr,
     Sunrise,
@@ -0,0 +1,300 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{PoisonError, RwLock};
+
+use crate::error::RegistryError;
+
+use super::is_named;
+use super::method::Method;
+use super::method_info::TwilightModel;
+use super::parameters::Parameters;
+
+// The methods that have been registered. They are kept for the
+// lifetime of the program, and never change, so that their ids
+// can be copied around like the built-in methods.
+static METHODS: RwLock<Vec<&'static CustomMethod>> = RwLock::new(Vec::new());
+
+/// The id of a method that has been registered with
+/// the [MethodRegistry](struct.MethodRegistry.html).
+#[derive(Copy, Clone)]
+pub struct MethodId(&'static CustomMethod);
+
+impl MethodId {
+    pub fn as_str(&self) -> &'static str {
+        &self.0.id
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        &self.0.name
+    }
+
+    pub(crate) fn parameters(&self) -> Parameters {
+        self.0.parameters
+    }
+
+    pub(crate) fn twilight(&self) -> TwilightModel {
+        self.0.twilight
+    }
+}
+
+// The ids are unique, so the methods are compared by their id.
+impl PartialEq for MethodId {
+    fn eq(&self, other: &MethodId) -> bool {
+        self.0.id == other.0.id
+    }
+}
+
+impl Eq for MethodId {}
+
+impl Hash for MethodId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.id.hash(state);
+    }
+}
+
+impl fmt::Debug for MethodId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MethodId").field(&self.0.id).finish()
+    }
+}
+
+impl fmt::Display for MethodId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.id)
+    }
+}
+
+/// A calculation method that is defined by the user, e.g.
+/// the timetable of a local mosque.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CustomMethod {
+    /// The id of the method, which is used for parsing
+    /// and serializing it.
+    pub id: String,
+
+    /// The name of the method, for display.
+    pub name: String,
+
+    pub parameters: Parameters,
+
+    /// How the method determines the twilight times of Fajr and Isha.
+    pub twilight: TwilightModel,
+}
+
+/// The calculation methods that are defined by the user.
+///
+/// Once registered, a method can be used like any of the built-in
+/// methods, e.g. `"my_mosque".parse::<Method>()` returns it, and it
+/// is the `method` of the [Parameters](struct.Parameters.html) that
+/// are returned by its `parameters()`.
+///
+/// A registered method can not be changed, so a `Method` always
+/// calculates the same times. A method with other settings needs
+/// an id of its own.
+pub struct MethodRegistry;
+
+impl MethodRegistry {
+    /// Registers the method. The id can not be empty or the name of a
+    /// built-in method, and the parameters should not have any errors.
+    ///
+    /// Registering the same method again, e.g. when a configuration is
+    /// reloaded, returns the method that was registered before. If the
+    /// id is registered with other settings [RegistryError::IdInUse](enum.RegistryError.html)
+    /// is returned.
+    pub fn register(method: CustomMethod) -> Result<Method, RegistryError> {
+        let is_built_in = Method::all()
+            .iter()
+            .any(|built_in| is_named(&method.id, &built_in.to_string()));
+
+        if method.id.is_empty() || is_built_in {
+            return Err(RegistryError::InvalidId);
+        }
+
+        if let Some(issue) = method
+            .parameters
+            .validate()
+            .into_iter()
+            .find(|issue| issue.is_error())
+        {
+            return Err(RegistryError::InvalidParameters(issue));
+        }
+
+        let mut methods = METHODS.write().unwrap_or_else(PoisonError::into_inner);
+
+        match methods
+            .iter()
+            .find(|registered| is_named(&method.id, &registered.id))
+        {
+            Some(registered)
+                if registered.name == method.name
+                    && registered.parameters == method.parameters
+                    && registered.twilight == method.twilight =>
+            {
+                Ok(Method::Custom(MethodId(registered)))
+            }
+            Some(_) => Err(RegistryError::IdInUse),
+            None => {
+                let registered: &'static CustomMethod = Box::leak(Box::new(method));
+
+                methods.push(registered);
+
+                Ok(Method::Custom(MethodId(registered)))
+            }
+        }
+    }
+
+    /// Returns the registered method with the given id.
+    pub fn get(id: &str) -> Option<Method> {
+        METHODS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|registered| is_named(id, &registered.id))
+            .map(|registered| Method::Custom(MethodId(registered)))
+    }
+
+    /// Returns all registered methods, in the order
+    /// they were registered.
+    pub fn methods() -> Vec<Method> {
+        METHODS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|registered| Method::Custom(MethodId(registered)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astronomy::unit::Coordinates;
+    use crate::models::parameters::Configuration;
+    use crate::models::prayer::Prayer;
+    use crate::schedule::PrayerTimes;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn register_custom_method() {
+        let parameters = Configuration::new(17.0, 16.0).done();
+        let method = MethodRegistry::register(CustomMethod {
+            id: String::from("test_mosque"),
+            name: String::from("Test Mosque"),
+            parameters,
+            twilight: TwilightModel::Standard,
+        })
+        .expect("Method could not be registered");
+
+        assert_eq!(method.to_string(), "test_mosque");
+        assert_eq!(method.info().name, "Test Mosque");
+        assert_eq!(MethodRegistry::get("test_mosque"), Some(method));
+        assert_eq!("test-mosque".parse::<Method>(), Ok(method));
+        assert!(MethodRegistry::methods().contains(&method));
+
+        let params = method.parameters();
+
+        assert_eq!(params.method, method);
+        assert_eq!(params.fajr_angle, 17.0);
+        assert_eq!(params.isha_angle, 16.0);
+    }
+
+    #[test]
+    fn register_custom_method_again() {
+        let register = |fajr_angle| {
+            MethodRegistry::register(CustomMethod {
+                id: String::from("test_registered_again"),
+                name: String::from("Test Registered Again"),
+                parameters: Configuration::new(fajr_angle, 17.0).done(),
+                twilight: TwilightModel::Seasonal,
+            })
+        };
+        let method = register(18.0).expect("Method could not be registered");
+        let params = method.parameters();
+
+        assert_eq!(register(18.0), Ok(method));
+        assert_eq!(register(19.0), Err(RegistryError::IdInUse));
+        assert_eq!(method.parameters(), params);
+        assert_eq!(method.parameters().fajr_angle, 18.0);
+        assert_eq!(method.twilight(), TwilightModel::Seasonal);
+        assert_eq!(
+            MethodRegistry::methods()
+                .iter()
+                .filter(|registered| **registered == method)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn register_invalid_custom_method() {
+        let custom = |id: &str, parameters| CustomMethod {
+            id: String::from(id),
+            name: String::from("Test"),
+            parameters,
+            twilight: TwilightModel::Standard,
+        };
+        let parameters = Configuration::new(18.0, 17.0).done();
+
+        assert_eq!(
+            MethodRegistry::register(custom("", parameters)),
+            Err(RegistryError::InvalidId)
+        );
+        assert_eq!(
+            MethodRegistry::register(custom("MuslimWorldLeague", parameters)),
+            Err(RegistryError::InvalidId)
+        );
+        assert!(matches!(
+            MethodRegistry::register(custom("test_invalid", Configuration::new(18.0, 0.0).done())),
+            Err(RegistryError::InvalidParameters(_))
+        ));
+        assert_eq!(MethodRegistry::get("test_invalid"), None);
+    }
+
+    #[test]
+    fn custom_method_with_seasonal_twilight() {
+        let date = NaiveDate::from_ymd_opt(2016, 1, 31).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let moonsighting = Method::MoonsightingCommittee.parameters();
+        let method = MethodRegistry::register(CustomMethod {
+            id: String::from("test_seasonal"),
+            name: String::from("Test Seasonal"),
+            parameters: moonsighting,
+            twilight: TwilightModel::Seasonal,
+        })
+        .expect("Method could not be registered");
+        let expected = PrayerTimes::new(date, coordinates, moonsighting)
+            .expect("Prayer times could not be calculated");
+        let times = PrayerTimes::new(date, coordinates, method.parameters())
+            .expect("Prayer times could not be calculated");
+
+        for prayer in [Prayer::Fajr, Prayer::Isha] {
+            assert_eq!(times.time(prayer), expected.time(prayer));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_custom_method() {
+        let method = MethodRegistry::register(CustomMethod {
+            id: String::from("test_serialized"),
+            name: String::from("Test Serialized"),
+            parameters: Configuration::new(18.0, 17.0).done(),
+            twilight: TwilightModel::Standard,
+        })
+        .expect("Method could not be registered");
+        let json = serde_json::to_value(method.parameters()).unwrap();
+
+        assert_eq!(json["method"], "test_serialized");
+        assert_eq!(
+            serde_json::from_value::<Parameters>(json).unwrap(),
+            method.parameters()
+        );
+        assert!(serde_json::from_str::<Method>("\"test_unregistered\"").is_err());
+    }
+}
@@ -4,9 +4,66 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+use super::is_named;
+
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Rounding {
     Nearest,
     Up,
+    Down,
+
+    /// Rounds the prayer times up and the time of sunrise down, so
+    /// that the times are on the side of caution (ihtiyat). Unlike
+    /// `Up`, a time that is on the minute is left as it is.
+    Ihtiyat,
     None,
 }
+
+impl Rounding {
+    // Returns the rounding for the time of sunrise, which ends
+    // the time of Fajr rather than starting a prayer.
+    pub(crate) fn for_sunrise(self) -> Rounding {
+        match self {
+            Rounding::Ihtiyat => Rounding::Down,
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Rounding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Rounding::Nearest => "nearest",
+            Rounding::Up => "up",
+            Rounding::Down => "down",
+            Rounding::Ihtiyat => "ihtiyat",
+            Rounding::None => "none",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Rounding {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<Rounding, ParseError> {
+        [
+            Rounding::Nearest,
+            Rounding::Up,
+            Rounding::Down,
+            Rounding::Ihtiyat,
+            Rounding::None,
+        ]
+        .into_iter()
+        .find(|value| is_named(name, &value.to_string()))
+        .ok_or_else(|| ParseError::new("rounding", name))
+    }
+}
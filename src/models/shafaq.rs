@@ -4,10 +4,19 @@
 // Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
 //
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+use super::is_named;
+
 /// Shafaq is the twilight in the sky. Different madhabs define the appearance of
 /// twilight differently. These values are used by the MoonsightingComittee method
 /// for the different ways to calculate Isha.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Shafaq {
     /// General is a combination of Ahmer and Abyad.
     General,
@@ -20,3 +29,26 @@ pub enum Shafaq {
     /// Used by the Hanafi madhab.
     Abyad,
 }
+
+impl fmt::Display for Shafaq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Shafaq::General => "general",
+            Shafaq::Ahmer => "ahmer",
+            Shafaq::Abyad => "abyad",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Shafaq {
+    type Err = ParseError;
+
+    fn from_str(name: &str) -> Result<Shafaq, ParseError> {
+        [Shafaq::General, Shafaq::Ahmer, Shafaq::Abyad]
+            .into_iter()
+            .find(|value| is_named(name, &value.to_string()))
+            .ok_or_else(|| ParseError::new("shafaq", name))
+    }
+}
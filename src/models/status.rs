@@ -0,0 +1,21 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+/// Describes how the time for a prayer was determined.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PrayerStatus {
+    /// The time was calculated from the position of the sun on the given date.
+    Computed,
+
+    /// The time was derived using a fallback rule, either the high
+    /// latitude rule or the [PolarResolution](enum.PolarResolution.html).
+    Derived,
+
+    /// The prayer has no time on the given date and location.
+    Undefined,
+}
@@ -0,0 +1,91 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use std::fmt;
+
+use super::prayer::Prayer;
+
+/// Whether an issue with the parameters still allows
+/// the prayer times to be calculated.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Severity {
+    /// The prayer times can be calculated, but are
+    /// probably not what was intended.
+    Warning,
+
+    /// The prayer times would be wrong, so they
+    /// are not calculated.
+    Error,
+}
+
+/// An issue that is found when validating the
+/// [Parameters](struct.Parameters.html).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ParameterIssue {
+    /// The angle for the prayer is negative, or is not below 90°.
+    AngleOutOfRange { prayer: Prayer, angle: f64 },
+
+    /// No angle is set for Fajr, or neither an angle nor an
+    /// interval is set for Isha.
+    MissingAngle(Prayer),
+
+    /// The `TwilightAngle` high latitude rule is used, but the
+    /// angle it depends on for the prayer is not set.
+    TwilightAngleWithoutAngle(Prayer),
+
+    /// The interval for Isha is negative, or is not within a day.
+    IshaIntervalOutOfRange(i32),
+
+    /// Both an angle and an interval are set for Isha,
+    /// in which case the angle is ignored.
+    IshaAngleAndInterval,
+
+    /// The adjustments of the prayer add up to more than an hour.
+    LargeAdjustment { prayer: Prayer, minutes: i64 },
+
+    /// The adjustments of the prayer add up to more than three hours.
+    AdjustmentOutOfRange { prayer: Prayer, minutes: i64 },
+}
+
+impl ParameterIssue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            ParameterIssue::IshaAngleAndInterval | ParameterIssue::LargeAdjustment { .. } => {
+                Severity::Warning
+            }
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+}
+
+impl fmt::Display for ParameterIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParameterIssue::AngleOutOfRange { prayer, angle } => {
+                write!(f, "The angle for {:?} is out of range: {}", prayer, angle)
+            }
+            ParameterIssue::MissingAngle(prayer) => write!(f, "No angle is set for {:?}", prayer),
+            ParameterIssue::TwilightAngleWithoutAngle(prayer) => {
+                write!(f, "The twilight angle rule needs an angle for {:?}", prayer)
+            }
+            ParameterIssue::IshaIntervalOutOfRange(interval) => {
+                write!(f, "The interval for Isha is out of range: {}", interval)
+            }
+            ParameterIssue::IshaAngleAndInterval => write!(
+                f,
+                "Both an angle and an interval are set for Isha, the angle is ignored"
+            ),
+            ParameterIssue::LargeAdjustment { prayer, minutes }
+            | ParameterIssue::AdjustmentOutOfRange { prayer, minutes } => {
+                write!(f, "The adjustment of {:?} is {} minutes", prayer, minutes)
+            }
+        }
+    }
+}
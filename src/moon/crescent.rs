@@ -0,0 +1,480 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::astronomy::lunar::{self, LunarCoordinates};
+use crate::astronomy::ops;
+use crate::astronomy::solar::{SolarCoordinates, SolarTime};
+use crate::astronomy::unit::{Angle, Coordinates};
+use crate::error::CrescentError;
+
+/// The visibility of the crescent by the q-test of Yallop
+/// (NAO Technical Note 69), from A to F.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum YallopVisibility {
+    /// (A) Easily visible to the naked eye, `q > 0.216`.
+    EasilyVisible,
+
+    /// (B) Visible under perfect conditions, `q > -0.014`.
+    VisibleUnderPerfectConditions,
+
+    /// (C) May need an optical aid to find the crescent,
+    /// `q > -0.160`.
+    OpticalAidToFind,
+
+    /// (D) Will need an optical aid to find the crescent,
+    /// `q > -0.232`.
+    OpticalAidOnly,
+
+    /// (E) Not visible with a telescope, `q > -0.293`.
+    NotVisibleWithTelescope,
+
+    /// (F) Not visible, below the Danjon limit. Also the visibility
+    /// when the moon sets before the sun, or when the new moon is
+    /// after the sunset.
+    NotVisible,
+}
+
+impl YallopVisibility {
+    /// The letter of the visibility, from `A` to `F`.
+    pub fn code(&self) -> char {
+        match self {
+            YallopVisibility::EasilyVisible => 'A',
+            YallopVisibility::VisibleUnderPerfectConditions => 'B',
+            YallopVisibility::OpticalAidToFind => 'C',
+            YallopVisibility::OpticalAidOnly => 'D',
+            YallopVisibility::NotVisibleWithTelescope => 'E',
+            YallopVisibility::NotVisible => 'F',
+        }
+    }
+}
+
+/// The visibility of the crescent by the criterion of Odeh
+/// (Experimental Astronomy 18, 2004), from A to D.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum OdehVisibility {
+    /// (A) Visible to the naked eye, `V >= 5.65`.
+    NakedEye,
+
+    /// (B) Visible with an optical aid, and could be
+    /// seen by the naked eye, `V >= 2.0`.
+    OpticalAidOrNakedEye,
+
+    /// (C) Visible with an optical aid only, `V >= -0.96`.
+    OpticalAidOnly,
+
+    /// (D) Not visible, even with an optical aid. Also the
+    /// visibility when the moon sets before the sun, or when
+    /// the new moon is after the sunset.
+    NotVisible,
+}
+
+impl OdehVisibility {
+    /// The letter of the visibility, from `A` to `D`.
+    pub fn code(&self) -> char {
+        match self {
+            OdehVisibility::NakedEye => 'A',
+            OdehVisibility::OpticalAidOrNakedEye => 'B',
+            OdehVisibility::OpticalAidOnly => 'C',
+            OdehVisibility::NotVisible => 'D',
+        }
+    }
+}
+
+/// The lunar crescent on the evening of a date at a location, at the
+/// best time for sighting it. The angles are in degrees and without
+/// the refraction of the atmosphere.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crescent {
+    /// The time of the new moon (conjunction) that is nearest
+    /// to the sunset.
+    pub conjunction: DateTime<Utc>,
+
+    /// The time of the sunset.
+    pub sunset: DateTime<Utc>,
+
+    /// The time of the moonset that is nearest to the sunset,
+    /// which is before the sunset when the moon sets first.
+    pub moonset: DateTime<Utc>,
+
+    /// The best time for sighting the crescent by Yallop, four
+    /// ninths of the lag after the sunset. It is the sunset when
+    /// the moon sets first.
+    pub best_time: DateTime<Utc>,
+
+    /// The time between the sunset and the moonset in minutes,
+    /// which is negative when the moon sets first.
+    pub lag: f64,
+
+    /// The time since the new moon at the best time in hours,
+    /// which is negative when the new moon is after the sunset.
+    pub age: f64,
+
+    /// The angle between the centers of the sun and the
+    /// moon, as seen from the center of the Earth (ARCL).
+    pub elongation: f64,
+
+    /// The difference between the altitudes of the centers of the
+    /// moon and the sun, as seen from the center of the Earth (ARCV).
+    pub arc_of_vision: f64,
+
+    /// The azimuth of the sun minus the azimuth of the moon (DAZ).
+    pub relative_azimuth: f64,
+
+    /// The angle between the centers of the sun and
+    /// the moon, as seen by the observer.
+    pub topocentric_elongation: f64,
+
+    /// The difference between the altitudes of the centers of
+    /// the moon and the sun, as seen by the observer.
+    pub topocentric_arc_of_vision: f64,
+
+    /// The semi-diameter of the moon, as seen by the
+    /// observer, in minutes of arc.
+    pub semi_diameter: f64,
+
+    /// The width of the crescent, as seen by the observer,
+    /// in minutes of arc.
+    pub width: f64,
+}
+
+impl Crescent {
+    /// Calculates the crescent on the evening of the date at the location.
+    pub fn new(date: NaiveDate, coordinates: Coordinates) -> Result<Crescent, CrescentError> {
+        if !coordinates.is_valid() {
+            return Err(CrescentError::InvalidCoordinates(coordinates));
+        }
+
+        let solar_date = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or(CrescentError::DateOutOfRange)?
+            .and_utc();
+        // The solar time can only fail for a date out of range.
+        let sunset = SolarTime::new(solar_date, coordinates)
+            .map_err(|_| CrescentError::DateOutOfRange)?
+            .sunset
+            .ok_or(CrescentError::NoSunsetOrMoonset)?;
+        let moonset =
+            Crescent::moonset(sunset, coordinates).ok_or(CrescentError::NoSunsetOrMoonset)?;
+        let lag = (moonset - sunset).num_seconds() as f64 / 60.0;
+
+        // Equation from NAO Technical Note 69 page 3
+        let best_time = if lag > 0.0 {
+            sunset + Duration::seconds((lag * 60.0 * 4.0 / 9.0).round() as i64)
+        } else {
+            sunset
+        };
+
+        let new_moon = Angle::new(0.0);
+        let previous =
+            lunar::phase_before(sunset, new_moon).ok_or(CrescentError::DateOutOfRange)?;
+        let next = lunar::phase_after(sunset, new_moon).ok_or(CrescentError::DateOutOfRange)?;
+        let conjunction = if sunset - previous <= next - sunset {
+            previous
+        } else {
+            next
+        };
+        let age = (best_time - conjunction).num_seconds() as f64 / 3600.0;
+
+        let julian_day = lunar::julian_day(best_time);
+        let ephemeris_day = lunar::julian_ephemeris_day(best_time);
+        let sidereal_time = lunar::apparent_sidereal_time(julian_day);
+        let local_hour_angle = |right_ascension: Angle| {
+            (sidereal_time + coordinates.longitude_angle() - right_ascension).unwound()
+        };
+        let latitude = coordinates.latitude_angle();
+
+        let solar = SolarCoordinates::new(ephemeris_day);
+        let solar_hour_angle = local_hour_angle(solar.right_ascension);
+        let solar_altitude =
+            ops::altitude_of_celestial_body(latitude, solar.declination, solar_hour_angle);
+        let solar_azimuth =
+            ops::azimuth_of_celestial_body(latitude, solar.declination, solar_hour_angle);
+
+        let lunar = LunarCoordinates::new(ephemeris_day);
+        let lunar_hour_angle = local_hour_angle(lunar.right_ascension);
+        let lunar_altitude =
+            ops::altitude_of_celestial_body(latitude, lunar.declination, lunar_hour_angle);
+        let lunar_azimuth =
+            ops::azimuth_of_celestial_body(latitude, lunar.declination, lunar_hour_angle);
+
+        let (right_ascension, declination) = lunar.topocentric(coordinates, lunar_hour_angle);
+        let topocentric_altitude = ops::altitude_of_celestial_body(
+            latitude,
+            declination,
+            local_hour_angle(right_ascension),
+        );
+
+        let elongation = Crescent::separation(
+            (solar.right_ascension, solar.declination),
+            (lunar.right_ascension, lunar.declination),
+        );
+        let topocentric_elongation = Crescent::separation(
+            (solar.right_ascension, solar.declination),
+            (right_ascension, declination),
+        );
+
+        // Equations from NAO Technical Note 69 page 4
+        let semi_diameter = lunar.semi_diameter().degrees
+            * 60.0
+            * (1.0 + (topocentric_altitude.radians().sin() * lunar.parallax().radians().sin()));
+        let width = semi_diameter * (1.0 - topocentric_elongation.radians().cos());
+
+        Ok(Crescent {
+            conjunction,
+            sunset,
+            moonset,
+            best_time,
+            lag,
+            age,
+            elongation: elongation.degrees,
+            arc_of_vision: (lunar_altitude - solar_altitude).degrees,
+            relative_azimuth: (solar_azimuth - lunar_azimuth).quadrant_shifted().degrees,
+            topocentric_elongation: topocentric_elongation.degrees,
+            topocentric_arc_of_vision: (topocentric_altitude - solar_altitude).degrees,
+            semi_diameter,
+            width,
+        })
+    }
+
+    /// The q-value of Yallop, from the geocentric arc of vision and
+    /// the width of the crescent. Yallop measures the width with the
+    /// geocentric elongation, so it is a little smaller than `width`.
+    pub fn q_value(&self) -> f64 {
+        // Equations from NAO Technical Note 69 page 4 and 5
+        let w = self.semi_diameter * (1.0 - Angle::new(self.elongation).radians().cos());
+        let limit = 11.8371 - (6.3226 * w) + (0.7319 * w.powf(2.0)) - (0.1018 * w.powf(3.0));
+
+        (self.arc_of_vision - limit) / 10.0
+    }
+
+    /// The V-value of Odeh, from the topocentric arc of
+    /// vision and the width of the crescent.
+    pub fn v_value(&self) -> f64 {
+        // Equation from Experimental Astronomy 18 page 51
+        let w = self.width;
+        let limit = (-0.1018 * w.powf(3.0)) + (0.7319 * w.powf(2.0)) - (6.3226 * w) + 7.1651;
+
+        self.topocentric_arc_of_vision - limit
+    }
+
+    /// The visibility of the crescent by the q-test of Yallop.
+    pub fn yallop(&self) -> YallopVisibility {
+        let q = self.q_value();
+
+        if !self.is_above_horizon() {
+            YallopVisibility::NotVisible
+        } else if q > 0.216 {
+            YallopVisibility::EasilyVisible
+        } else if q > -0.014 {
+            YallopVisibility::VisibleUnderPerfectConditions
+        } else if q > -0.160 {
+            YallopVisibility::OpticalAidToFind
+        } else if q > -0.232 {
+            YallopVisibility::OpticalAidOnly
+        } else if q > -0.293 {
+            YallopVisibility::NotVisibleWithTelescope
+        } else {
+            YallopVisibility::NotVisible
+        }
+    }
+
+    /// The visibility of the crescent by the criterion of Odeh.
+    pub fn odeh(&self) -> OdehVisibility {
+        let v = self.v_value();
+
+        if !self.is_above_horizon() {
+            OdehVisibility::NotVisible
+        } else if v >= 5.65 {
+            OdehVisibility::NakedEye
+        } else if v >= 2.0 {
+            OdehVisibility::OpticalAidOrNakedEye
+        } else if v >= -0.96 {
+            OdehVisibility::OpticalAidOnly
+        } else {
+            OdehVisibility::NotVisible
+        }
+    }
+
+    // The crescent can only be seen when the moon sets after
+    // the sun, and the new moon is before the sunset.
+    fn is_above_horizon(&self) -> bool {
+        self.lag > 0.0 && self.conjunction < self.sunset
+    }
+
+    // The angle between two bodies from their right ascensions
+    // and declinations.
+    fn separation(first: (Angle, Angle), second: (Angle, Angle)) -> Angle {
+        // Equation from Astronomical Algorithms page 109
+        let (first_ascension, first_declination) = (first.0.radians(), first.1.radians());
+        let (second_ascension, second_declination) = (second.0.radians(), second.1.radians());
+        let term1 = first_declination.sin() * second_declination.sin();
+        let term2 = first_declination.cos()
+            * second_declination.cos()
+            * (first_ascension - second_ascension).cos();
+
+        Angle::from_radians((term1 + term2).clamp(-1.0, 1.0).acos())
+    }
+
+    // The moonset that is nearest to the sunset, within a day
+    // before or after it.
+    fn moonset(sunset: DateTime<Utc>, coordinates: Coordinates) -> Option<DateTime<Utc>> {
+        let step = Duration::minutes(20);
+        let is_up = Crescent::altitude_above_moonset(sunset, coordinates) > 0.0;
+        let mut time = sunset;
+
+        for _ in 0..(24 * 3) {
+            let next = if is_up { time + step } else { time - step };
+            let is_next_up = Crescent::altitude_above_moonset(next, coordinates) > 0.0;
+
+            if is_up && !is_next_up {
+                return Some(Crescent::bisect(time, next, coordinates));
+            } else if !is_up && is_next_up {
+                return Some(Crescent::bisect(next, time, coordinates));
+            } else {
+                time = next;
+            }
+        }
+
+        None
+    }
+
+    // Narrows down the moonset between a time at which the
+    // moon is up and a later time at which it has set.
+    fn bisect(
+        before: DateTime<Utc>,
+        after: DateTime<Utc>,
+        coordinates: Coordinates,
+    ) -> DateTime<Utc> {
+        let (mut before, mut after) = (before, after);
+
+        while after - before > Duration::seconds(1) {
+            let middle = before + Duration::seconds((after - before).num_seconds() / 2);
+
+            if Crescent::altitude_above_moonset(middle, coordinates) > 0.0 {
+                before = middle;
+            } else {
+                after = middle;
+            }
+        }
+
+        after
+    }
+
+    // The altitude of the center of the moon, relative to its altitude
+    // at the moonset, which accounts for the refraction, the
+    // semi-diameter and the parallax of the moon.
+    fn altitude_above_moonset(time: DateTime<Utc>, coordinates: Coordinates) -> f64 {
+        let lunar = LunarCoordinates::for_time(time);
+        let sidereal_time = lunar::apparent_sidereal_time(lunar::julian_day(time));
+        let local_hour_angle =
+            (sidereal_time + coordinates.longitude_angle() - lunar.right_ascension).unwound();
+        let altitude = ops::altitude_of_celestial_body(
+            coordinates.latitude_angle(),
+            lunar.declination,
+            local_hour_angle,
+        );
+
+        // Equation from Astronomical Algorithms page 102
+        let moonset_altitude = (0.7275 * lunar.parallax().degrees) - (34.0 / 60.0);
+
+        altitude.degrees - moonset_altitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date provided")
+    }
+
+    #[test]
+    fn crescent_after_new_moon() {
+        // The crescent of Ramadan 1444 in Makkah, the evening
+        // after the new moon of 2023-03-21 at 17:23 UTC.
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let crescent = Crescent::new(date(2023, 3, 22), coordinates)
+            .expect("Crescent could not be calculated");
+
+        assert_eq!(
+            crescent.conjunction.format("%Y-%m-%d %H:%M").to_string(),
+            "2023-03-21 17:23"
+        );
+        assert_eq!(crescent.sunset.format("%H:%M").to_string(), "15:32");
+        assert_eq!(crescent.moonset.format("%H:%M").to_string(), "16:23");
+        assert_eq!(crescent.best_time.format("%H:%M").to_string(), "15:54");
+        assert!((crescent.lag - 51.5).abs() < 0.1);
+        assert!((crescent.age - 22.5).abs() < 0.1);
+        assert!((crescent.elongation - 12.7).abs() < 0.1);
+        assert!((crescent.arc_of_vision - 12.6).abs() < 0.1);
+        assert!((crescent.topocentric_arc_of_vision - 11.6).abs() < 0.1);
+        assert!((crescent.width - 0.34).abs() < 0.01);
+        assert!((crescent.q_value() - 0.319).abs() < 0.005);
+        assert!((crescent.v_value() - 6.53).abs() < 0.05);
+        assert_eq!(crescent.yallop(), YallopVisibility::EasilyVisible);
+        assert_eq!(crescent.odeh(), OdehVisibility::NakedEye);
+    }
+
+    #[test]
+    fn crescent_before_new_moon() {
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let crescent = Crescent::new(date(2023, 3, 21), coordinates)
+            .expect("Crescent could not be calculated");
+
+        assert!(crescent.lag < 0.0);
+        assert!(crescent.age < 0.0);
+        assert_eq!(crescent.best_time, crescent.sunset);
+        assert_eq!(crescent.yallop(), YallopVisibility::NotVisible);
+        assert_eq!(crescent.odeh(), OdehVisibility::NotVisible);
+    }
+
+    #[test]
+    fn crescent_needing_optical_aid() {
+        // The crescent of Shawwal 1444, on 2023-04-20, could not
+        // be seen in Makkah but could be seen with an optical aid in Lima.
+        let makkah = Crescent::new(date(2023, 4, 20), Coordinates::new(21.4225, 39.8262))
+            .expect("Crescent could not be calculated");
+        let lima = Crescent::new(date(2023, 4, 20), Coordinates::new(-12.0464, -77.0428))
+            .expect("Crescent could not be calculated");
+
+        assert_eq!(makkah.yallop(), YallopVisibility::NotVisible);
+        assert_eq!(makkah.odeh(), OdehVisibility::NotVisible);
+        assert!(lima.q_value() > makkah.q_value());
+        assert!(lima.v_value() > makkah.v_value());
+        assert_eq!(lima.odeh(), OdehVisibility::OpticalAidOnly);
+    }
+
+    #[test]
+    fn crescent_visibility_codes() {
+        assert_eq!(YallopVisibility::EasilyVisible.code(), 'A');
+        assert_eq!(YallopVisibility::NotVisible.code(), 'F');
+        assert_eq!(OdehVisibility::NakedEye.code(), 'A');
+        assert_eq!(OdehVisibility::NotVisible.code(), 'D');
+    }
+
+    #[test]
+    fn crescent_without_sunset() {
+        let tromso = Coordinates::new(69.6492, 18.9553);
+        let invalid = Coordinates::new(95.0, 18.9553);
+
+        assert_eq!(
+            Crescent::new(date(2022, 6, 21), tromso),
+            Err(CrescentError::NoSunsetOrMoonset)
+        );
+        assert_eq!(
+            Crescent::new(date(2022, 6, 21), invalid),
+            Err(CrescentError::InvalidCoordinates(invalid))
+        );
+    }
+}
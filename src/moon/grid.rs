@@ -0,0 +1,238 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::astronomy::unit::Coordinates;
+use crate::error::CrescentError;
+use crate::moon::crescent::Crescent;
+
+// The most locations that a grid can have, e.g. every 0.25°
+// from 60° south to 60° north, which keeps a grid with a tiny
+// step from allocating more than the memory can hold.
+const MAX_LOCATIONS: f64 = 1_000_000.0;
+
+/// A grid of locations for mapping the zones in which the crescent
+/// can be seen on the evening of a date, e.g. the day of a new moon
+/// and the day after it.
+///
+/// By default the grid covers the latitudes from 60° south to 60°
+/// north and all of the longitudes, every 2°.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct VisibilityGrid {
+    date: NaiveDate,
+    latitudes: (f64, f64),
+    longitudes: (f64, f64),
+    step: f64,
+}
+
+impl VisibilityGrid {
+    pub fn new(date: NaiveDate) -> VisibilityGrid {
+        VisibilityGrid {
+            date,
+            latitudes: (-60.0, 60.0),
+            longitudes: (-180.0, 180.0),
+            step: 2.0,
+        }
+    }
+
+    /// Limits the grid to the latitudes from `south` to `north`.
+    pub fn latitudes(mut self, south: f64, north: f64) -> VisibilityGrid {
+        self.latitudes = (south, north);
+        self
+    }
+
+    /// Limits the grid to the longitudes from `west` to `east`.
+    pub fn longitudes(mut self, west: f64, east: f64) -> VisibilityGrid {
+        self.longitudes = (west, east);
+        self
+    }
+
+    /// Returns the grid for the first evening after the conjunction,
+    /// e.g. from `MoonPhase::NewMoon.time_after(time)`. This is the
+    /// evening of the date of the conjunction in UTC, on which the
+    /// crescent can be seen to the west of where the sun sets after
+    /// the conjunction. Use `new` with the next date for the evening
+    /// after it.
+    pub fn after_conjunction(conjunction: DateTime<Utc>) -> VisibilityGrid {
+        VisibilityGrid::new(conjunction.date_naive())
+    }
+
+    /// Sets the distance between the locations of the grid in degrees.
+    /// A grid that would have more than a million locations for the
+    /// step has none.
+    pub fn step(mut self, degrees: f64) -> VisibilityGrid {
+        self.step = degrees;
+        self
+    }
+
+    /// Returns the locations of the grid, from south to north and
+    /// from west to east. When the longitudes go all the way around
+    /// the Earth, the east end is left out as it is the west end.
+    ///
+    /// The grid has no locations when the step is not positive or
+    /// when it would have more than a million locations.
+    pub fn locations(&self) -> Vec<Coordinates> {
+        if self.step.is_nan() || self.step <= 0.0 {
+            return vec![];
+        }
+
+        let step = self.step;
+        let values = move |start: f64, count: f64| {
+            (0..count.max(0.0) as i64).map(move |index| start + (index as f64 * step))
+        };
+        let (west, east) = self.longitudes;
+        let longitudes = if east - west >= 360.0 {
+            (360.0 / step).ceil()
+        } else {
+            ((east - west) / step).floor() + 1.0
+        };
+        let (south, north) = self.latitudes;
+        let latitudes = ((north - south) / step).floor() + 1.0;
+
+        if latitudes.max(0.0) * longitudes.max(0.0) > MAX_LOCATIONS {
+            return vec![];
+        } else {
+            // Nothing to do.
+        }
+
+        values(south, latitudes)
+            .flat_map(|latitude| {
+                values(west, longitudes).map(move |longitude| Coordinates::new(latitude, longitude))
+            })
+            .collect()
+    }
+
+    /// Calculates the crescent at each of the locations of the grid,
+    /// in the same order as the locations. When the `rayon` feature is
+    /// enabled the locations are calculated in parallel.
+    pub fn calculate(&self) -> Vec<(Coordinates, Result<Crescent, CrescentError>)> {
+        let calculate =
+            |coordinates: Coordinates| (coordinates, Crescent::new(self.date, coordinates));
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            self.locations().into_par_iter().map(calculate).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.locations().into_iter().map(calculate).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moon::crescent::{OdehVisibility, YallopVisibility};
+    use crate::moon::phase::MoonPhase;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date provided")
+    }
+
+    #[test]
+    fn grid_locations() {
+        let grid = VisibilityGrid::new(date(2023, 3, 22));
+        let locations = grid.locations();
+
+        assert_eq!(locations.len(), 61 * 180);
+        assert_eq!(locations[0], Coordinates::new(-60.0, -180.0));
+        assert_eq!(locations[1], Coordinates::new(-60.0, -178.0));
+        assert_eq!(locations[179], Coordinates::new(-60.0, 178.0));
+        assert_eq!(locations[180], Coordinates::new(-58.0, -180.0));
+
+        let region = grid.latitudes(20.0, 30.0).longitudes(30.0, 50.0).step(5.0);
+
+        assert_eq!(
+            region.locations(),
+            vec![
+                Coordinates::new(20.0, 30.0),
+                Coordinates::new(20.0, 35.0),
+                Coordinates::new(20.0, 40.0),
+                Coordinates::new(20.0, 45.0),
+                Coordinates::new(20.0, 50.0),
+                Coordinates::new(25.0, 30.0),
+                Coordinates::new(25.0, 35.0),
+                Coordinates::new(25.0, 40.0),
+                Coordinates::new(25.0, 45.0),
+                Coordinates::new(25.0, 50.0),
+                Coordinates::new(30.0, 30.0),
+                Coordinates::new(30.0, 35.0),
+                Coordinates::new(30.0, 40.0),
+                Coordinates::new(30.0, 45.0),
+                Coordinates::new(30.0, 50.0),
+            ]
+        );
+        assert!(grid.step(0.0).locations().is_empty());
+        assert!(grid.step(1e-9).locations().is_empty());
+        assert_eq!(grid.step(0.25).locations().len(), 481 * 1440);
+    }
+
+    #[test]
+    fn grid_after_conjunction() {
+        let conjunction = MoonPhase::NewMoon
+            .time_after(
+                date(2023, 3, 15)
+                    .and_hms_opt(0, 0, 0)
+                    .expect("Invalid time provided")
+                    .and_utc(),
+            )
+            .expect("New moon could not be calculated");
+
+        assert_eq!(
+            VisibilityGrid::after_conjunction(conjunction),
+            VisibilityGrid::new(date(2023, 3, 21))
+        );
+    }
+
+    #[test]
+    fn grid_matches_crescents() {
+        let grid = VisibilityGrid::new(date(2023, 4, 20))
+            .latitudes(-30.0, 30.0)
+            .step(30.0);
+        let points = grid.calculate();
+
+        assert_eq!(points.len(), 3 * 12);
+        for (coordinates, crescent) in points {
+            assert_eq!(crescent, Crescent::new(date(2023, 4, 20), coordinates));
+        }
+    }
+
+    #[test]
+    fn grid_visibility_zones() {
+        // The crescent of Shawwal 1444 is easier to see in the west.
+        let points = VisibilityGrid::new(date(2023, 4, 20))
+            .latitudes(0.0, 0.0)
+            .step(10.0)
+            .calculate();
+        let zone = |code: char| {
+            points
+                .iter()
+                .filter(|(_, crescent)| {
+                    crescent
+                        .as_ref()
+                        .is_ok_and(|crescent| crescent.yallop().code() == code)
+                })
+                .map(|(coordinates, _)| coordinates.longitude)
+                .collect::<Vec<f64>>()
+        };
+
+        assert!(zone('A').iter().all(|longitude| *longitude < -100.0));
+        assert!(zone('F').iter().all(|longitude| *longitude > -80.0));
+        assert!(!zone('A').is_empty() && !zone('F').is_empty());
+        assert!(points.iter().any(|(_, crescent)| {
+            crescent.as_ref().map(|crescent| crescent.odeh()) == Ok(OdehVisibility::OpticalAidOnly)
+        }));
+        assert!(points.iter().any(|(_, crescent)| {
+            crescent.as_ref().map(|crescent| crescent.yallop())
+                == Ok(YallopVisibility::VisibleUnderPerfectConditions)
+        }));
+    }
+}
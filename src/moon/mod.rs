@@ -0,0 +1,19 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Moon
+//!
+//! This module calculates the position and the phases of the moon,
+//! the times of the moonrise and the moonset, and predicts the
+//! visibility of the lunar crescent on the evening after a new moon,
+//! for a location or for a grid of locations, by the criteria of
+//! Yallop and of Odeh.
+
+pub(crate) mod crescent;
+pub(crate) mod grid;
+pub(crate) mod phase;
+pub(crate) mod position;
+pub(crate) mod times;
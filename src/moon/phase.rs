@@ -0,0 +1,106 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, Utc};
+
+use crate::astronomy::lunar;
+use crate::astronomy::unit::Angle;
+
+/// The principal phases of the moon.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MoonPhase {
+    /// The moon and the sun have the same longitude (conjunction).
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl MoonPhase {
+    /// Returns the time of the next phase after the given time.
+    pub fn time_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        lunar::phase_after(time, self.elongation())
+    }
+
+    /// Returns the time of the last phase at, or before, the given time.
+    pub fn time_before(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        lunar::phase_before(time, self.elongation())
+    }
+
+    /// Returns the first of the principal phases after the given
+    /// time, and its time.
+    pub fn next(time: DateTime<Utc>) -> Option<(MoonPhase, DateTime<Utc>)> {
+        let phases = [
+            MoonPhase::NewMoon,
+            MoonPhase::FirstQuarter,
+            MoonPhase::FullMoon,
+            MoonPhase::LastQuarter,
+        ];
+
+        phases
+            .iter()
+            .filter_map(|phase| phase.time_after(time).map(|after| (*phase, after)))
+            .min_by_key(|(_, after)| *after)
+    }
+
+    // The elongation of the moon from the sun in longitude
+    // at the phase.
+    fn elongation(&self) -> Angle {
+        match self {
+            MoonPhase::NewMoon => Angle::new(0.0),
+            MoonPhase::FirstQuarter => Angle::new(90.0),
+            MoonPhase::FullMoon => Angle::new(180.0),
+            MoonPhase::LastQuarter => Angle::new(270.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().expect("Invalid date provided")
+    }
+
+    #[test]
+    fn calculate_phases() {
+        let phases = [
+            (MoonPhase::NewMoon, "2023-03-21T17:23:00Z"),
+            (MoonPhase::FirstQuarter, "2023-03-29T02:32:00Z"),
+            (MoonPhase::FullMoon, "2023-04-06T04:34:00Z"),
+            (MoonPhase::LastQuarter, "2023-04-13T09:11:00Z"),
+            (MoonPhase::FullMoon, "2024-12-15T09:02:00Z"),
+        ];
+
+        for (phase, expected) in phases {
+            let expected = time(expected);
+            let after = phase
+                .time_after(expected - chrono::Duration::days(10))
+                .expect("Invalid date provided");
+            let before = phase
+                .time_before(expected + chrono::Duration::days(10))
+                .expect("Invalid date provided");
+
+            assert!((after - expected).num_seconds().abs() < 120, "{}", after);
+            assert_eq!(before, after);
+        }
+    }
+
+    #[test]
+    fn next_phase() {
+        let (phase, next) =
+            MoonPhase::next(time("2023-03-25T00:00:00Z")).expect("Invalid date provided");
+
+        assert_eq!(phase, MoonPhase::FirstQuarter);
+        assert_eq!(
+            MoonPhase::next(next).map(|(phase, _)| phase),
+            Some(MoonPhase::FullMoon)
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, Utc};
+
+use crate::astronomy::lunar::{self, LunarCoordinates};
+use crate::astronomy::ops;
+use crate::astronomy::unit::{Angle, Coordinates};
+use crate::error::Error;
+
+/// The moon at a time, as seen from the center of the Earth.
+/// The angles are in degrees.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Moon {
+    pub time: DateTime<Utc>,
+
+    /// The apparent longitude of the moon on the ecliptic.
+    pub longitude: f64,
+
+    /// The latitude of the moon, the angle between
+    /// the moon and the ecliptic.
+    pub latitude: f64,
+
+    /// The distance between the centers of the Earth
+    /// and the moon in kilometers.
+    pub distance: f64,
+
+    pub right_ascension: f64,
+    pub declination: f64,
+
+    /// The elongation of the moon from the sun in longitude, which
+    /// is 0° at the new moon, 90° at the first quarter, 180° at the
+    /// full moon and 270° at the last quarter.
+    pub elongation: f64,
+
+    /// The angle between the sun and the Earth, as seen from the moon.
+    pub phase_angle: f64,
+
+    /// The fraction of the disk of the moon that is
+    /// illuminated, from 0.0 to 1.0.
+    pub illuminated_fraction: f64,
+
+    /// The time since the last new moon in hours.
+    pub age: f64,
+}
+
+/// The position of the moon as seen by an observer, without
+/// the refraction of the atmosphere. The angles are in degrees.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonPosition {
+    pub right_ascension: f64,
+    pub declination: f64,
+
+    /// The altitude of the center of the moon above the horizon.
+    pub altitude: f64,
+
+    /// The azimuth of the moon, measured eastward from the north.
+    pub azimuth: f64,
+}
+
+impl Moon {
+    pub fn new(time: DateTime<Utc>) -> Result<Moon, Error> {
+        let ephemeris_day = lunar::julian_ephemeris_day(time);
+        let julian_century = ops::julian_century(ephemeris_day);
+        let lunar = LunarCoordinates::new(ephemeris_day);
+        let elongation = lunar::elongation_in_longitude(ephemeris_day);
+
+        // Equations from Astronomical Algorithms page 345
+        let solar_longitude = ops::apparent_solar_longitude(
+            julian_century,
+            ops::mean_solar_longitude(julian_century),
+        );
+        let solar_distance = ops::solar_distance(julian_century) * 149597870.0;
+        let geocentric_elongation = (lunar.latitude.radians().cos()
+            * (lunar.longitude - solar_longitude).radians().cos())
+        .acos();
+        let phase_angle = Angle::from_radians(
+            (solar_distance * geocentric_elongation.sin())
+                .atan2(lunar.distance - (solar_distance * geocentric_elongation.cos())),
+        );
+        let illuminated_fraction = (1.0 + phase_angle.radians().cos()) / 2.0;
+
+        let new_moon = lunar::phase_before(time, Angle::new(0.0)).ok_or(Error::DateOutOfRange)?;
+
+        Ok(Moon {
+            time,
+            longitude: lunar.longitude.degrees,
+            latitude: lunar.latitude.degrees,
+            distance: lunar.distance,
+            right_ascension: lunar.right_ascension.degrees,
+            declination: lunar.declination.degrees,
+            elongation: elongation.degrees,
+            phase_angle: phase_angle.degrees,
+            illuminated_fraction,
+            age: (time - new_moon).num_seconds() as f64 / 3600.0,
+        })
+    }
+
+    /// Returns `true` from the new moon until the full moon.
+    pub fn is_waxing(&self) -> bool {
+        self.elongation < 180.0
+    }
+
+    /// The position of the moon as seen by an observer at the location,
+    /// which accounts for the parallax of the moon.
+    pub fn position(&self, coordinates: Coordinates) -> MoonPosition {
+        let lunar = LunarCoordinates {
+            longitude: Angle::new(self.longitude),
+            latitude: Angle::new(self.latitude),
+            distance: self.distance,
+            right_ascension: Angle::new(self.right_ascension),
+            declination: Angle::new(self.declination),
+        };
+        let sidereal_time = lunar::apparent_sidereal_time(lunar::julian_day(self.time));
+        let local_hour_angle = |right_ascension: Angle| {
+            (sidereal_time + coordinates.longitude_angle() - right_ascension).unwound()
+        };
+
+        let (right_ascension, declination) =
+            lunar.topocentric(coordinates, local_hour_angle(lunar.right_ascension));
+        let hour_angle = local_hour_angle(right_ascension);
+        let latitude = coordinates.latitude_angle();
+
+        MoonPosition {
+            right_ascension: right_ascension.degrees,
+            declination: declination.degrees,
+            altitude: ops::altitude_of_celestial_body(latitude, declination, hour_angle).degrees,
+            azimuth: ops::azimuth_of_celestial_body(latitude, declination, hour_angle).degrees,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().expect("Invalid date provided")
+    }
+
+    #[test]
+    fn calculate_moon() {
+        // Example from Astronomical Algorithms page 347, 1992 April 12
+        // at 0h in dynamical time, which was 59 seconds earlier in UTC.
+        let moon = Moon::new(time("1992-04-11T23:59:01Z")).expect("Invalid date provided");
+
+        assert!((moon.longitude - 133.1673).abs() < 0.001);
+        assert!((moon.latitude - -3.2291).abs() < 0.001);
+        assert!((moon.distance - 368410.0).abs() < 1.0);
+        assert!((moon.phase_angle - 69.0756).abs() < 0.001);
+        assert!((moon.illuminated_fraction - 0.6786).abs() < 0.0001);
+        assert!(moon.is_waxing());
+    }
+
+    #[test]
+    fn calculate_moon_age() {
+        let new_moon = Moon::new(time("2023-03-22T17:23:00Z")).expect("Invalid date provided");
+        let full_moon = Moon::new(time("2023-04-06T04:34:00Z")).expect("Invalid date provided");
+        let last_quarter = Moon::new(time("2023-04-13T09:11:00Z")).expect("Invalid date provided");
+
+        assert!((new_moon.age - 24.0).abs() < 0.1);
+        assert!(new_moon.illuminated_fraction < 0.05);
+        assert!((full_moon.age - 371.2).abs() < 0.1);
+        assert!(full_moon.illuminated_fraction > 0.99);
+        assert!((last_quarter.elongation - 270.0).abs() < 0.1);
+        assert!(!last_quarter.is_waxing());
+    }
+
+    #[test]
+    fn calculate_moon_position() {
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let moon = Moon::new(time("2023-03-22T16:00:00Z")).expect("Invalid date provided");
+        let position = moon.position(coordinates);
+        let setting = Moon::new(time("2023-03-22T16:23:00Z"))
+            .expect("Invalid date provided")
+            .position(coordinates);
+
+        // The crescent is low in the west after the sunset.
+        assert!(position.altitude > 4.0 && position.altitude < 7.0);
+        assert!(position.azimuth > 260.0 && position.azimuth < 280.0);
+        assert!(setting.altitude.abs() < 1.0);
+
+        // The parallax moves the moon away from the zenith.
+        assert!(position.declination < moon.declination);
+    }
+}
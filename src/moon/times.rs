@@ -0,0 +1,239 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+use chrono::{DateTime, Days, Duration, NaiveDate, Utc};
+
+use crate::astronomy::lunar::{self, LunarCoordinates};
+use crate::astronomy::ops;
+use crate::astronomy::unit::{Angle, Coordinates, Stride};
+use crate::error::Error;
+use crate::models::rounding::Rounding;
+
+/// The times of the moonrise, the transit and the moonset on a
+/// date at a location, rounded to the nearest minute.
+///
+/// The date is the mean solar day at the longitude of the location,
+/// from midnight to midnight. As the moon rises about 50 minutes
+/// later each day, one of the times can be `None` on some days. It
+/// is also `None` when the moon does not rise or set at all, e.g.
+/// near the poles.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonTimes {
+    pub rise: Option<DateTime<Utc>>,
+
+    /// The time at which the moon is at its highest point in the sky.
+    pub transit: Option<DateTime<Utc>>,
+
+    pub set: Option<DateTime<Utc>>,
+}
+
+impl MoonTimes {
+    pub fn new(date: NaiveDate, coordinates: Coordinates) -> Result<MoonTimes, Error> {
+        if !coordinates.is_valid() {
+            return Err(Error::InvalidCoordinates(coordinates));
+        }
+
+        let midnight = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or(Error::DateOutOfRange)?
+            .and_utc();
+        let start = midnight - Duration::seconds((coordinates.longitude * 240.0) as i64);
+        let end = start + Duration::days(1);
+
+        // Each day in UTC has at most one of each time, which can be
+        // on the date before or after it, so the times of the days
+        // around the date are collected.
+        let mut times = MoonTimes {
+            rise: None,
+            transit: None,
+            set: None,
+        };
+        let first = date
+            .checked_sub_days(Days::new(1))
+            .ok_or(Error::DateOutOfRange)?;
+
+        for day in first.iter_days().take(3) {
+            let day_times = MoonTimes::for_day(day, coordinates)?;
+            let earliest = |time: Option<DateTime<Utc>>, other: Option<DateTime<Utc>>| {
+                let other = other.filter(|other| *other >= start && *other < end);
+
+                match (time, other) {
+                    (Some(time), Some(other)) => Some(time.min(other)),
+                    (time, other) => time.or(other),
+                }
+            };
+
+            times.rise = earliest(times.rise, day_times.rise);
+            times.transit = earliest(times.transit, day_times.transit);
+            times.set = earliest(times.set, day_times.set);
+        }
+
+        Ok(times)
+    }
+
+    // The times that are calculated from the position of the moon
+    // at 0h UTC of the day, which can be outside of the day.
+    fn for_day(day: NaiveDate, coordinates: Coordinates) -> Result<MoonTimes, Error> {
+        let midnight = day
+            .and_hms_opt(0, 0, 0)
+            .ok_or(Error::DateOutOfRange)?
+            .and_utc();
+        let julian_day = midnight.julian_day();
+        let lunar = LunarCoordinates::for_time(midnight);
+        let prev_lunar = LunarCoordinates::for_time(midnight.yesterday());
+        let next_lunar = LunarCoordinates::for_time(midnight.tomorrow());
+        let sidereal_time = lunar::apparent_sidereal_time(julian_day);
+        let longitude = coordinates.longitude_angle();
+
+        // Equation from Astronomical Algorithms page 102
+        let moonset_altitude = Angle::new((0.7275 * lunar.parallax().degrees) - (34.0 / 60.0));
+
+        let approx_transit =
+            ops::approximate_transit(longitude, sidereal_time, lunar.right_ascension);
+
+        // The moon moves quickly, so the corrections are
+        // repeated until they settle.
+        let mut transit = approx_transit;
+        for _ in 0..3 {
+            transit = ops::corrected_transit(
+                transit,
+                longitude,
+                sidereal_time,
+                lunar.right_ascension,
+                prev_lunar.right_ascension,
+                next_lunar.right_ascension,
+            ) / 24.0;
+        }
+
+        // Equation from Astronomical Algorithms page 102
+        let term1 = moonset_altitude.radians().sin()
+            - (coordinates.latitude_angle().radians().sin() * lunar.declination.radians().sin());
+        let term2 =
+            coordinates.latitude_angle().radians().cos() * lunar.declination.radians().cos();
+        let hour_angle = (term1 / term2).acos().to_degrees() / 360.0;
+
+        let corrected = |after_transit: bool| {
+            let mut time = if after_transit {
+                approx_transit + hour_angle
+            } else {
+                approx_transit - hour_angle
+            };
+
+            for _ in 0..3 {
+                // The hour angle is added back to the transit by the correction.
+                let approx = if after_transit {
+                    time - hour_angle
+                } else {
+                    time + hour_angle
+                };
+
+                time = ops::corrected_hour_angle(
+                    approx,
+                    moonset_altitude,
+                    coordinates,
+                    after_transit,
+                    sidereal_time,
+                    lunar.right_ascension,
+                    prev_lunar.right_ascension,
+                    next_lunar.right_ascension,
+                    lunar.declination,
+                    prev_lunar.declination,
+                    next_lunar.declination,
+                ) / 24.0;
+            }
+
+            MoonTimes::time(midnight, time)
+        };
+
+        Ok(MoonTimes {
+            rise: corrected(false),
+            transit: MoonTimes::time(midnight, transit),
+            set: corrected(true),
+        })
+    }
+
+    // The time from a fraction of the day after midnight.
+    fn time(midnight: DateTime<Utc>, fraction: f64) -> Option<DateTime<Utc>> {
+        if fraction.is_finite() {
+            let seconds = (fraction * 86400.0).round() as i64;
+
+            Some((midnight + Duration::seconds(seconds)).rounded_minute(Rounding::Nearest))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date provided")
+    }
+
+    fn time(value: &str) -> Option<DateTime<Utc>> {
+        Some(value.parse().expect("Invalid date provided"))
+    }
+
+    #[test]
+    fn calculate_moon_times() {
+        let makkah = MoonTimes::new(date(2023, 3, 22), Coordinates::new(21.4225, 39.8262))
+            .expect("Moon times could not be calculated");
+        let london = MoonTimes::new(date(2023, 3, 22), Coordinates::new(51.5, -0.12))
+            .expect("Moon times could not be calculated");
+
+        assert_eq!(makkah.rise, time("2023-03-22T03:53:00Z"));
+        assert_eq!(makkah.transit, time("2023-03-22T10:05:00Z"));
+        assert_eq!(makkah.set, time("2023-03-22T16:23:00Z"));
+        assert_eq!(london.rise, time("2023-03-22T06:32:00Z"));
+        assert_eq!(london.transit, time("2023-03-22T12:51:00Z"));
+        assert_eq!(london.set, time("2023-03-22T19:28:00Z"));
+    }
+
+    #[test]
+    fn calculate_moon_times_across_utc_dates() {
+        // The moonrise in Sydney is on the date before in UTC,
+        // and the moonset in New York is on the date after.
+        let sydney = MoonTimes::new(date(2024, 6, 1), Coordinates::new(-33.8688, 151.2093))
+            .expect("Moon times could not be calculated");
+        let new_york = MoonTimes::new(date(2024, 1, 15), Coordinates::new(40.7128, -74.0059))
+            .expect("Moon times could not be calculated");
+
+        assert_eq!(sydney.rise, time("2024-05-31T14:40:00Z"));
+        assert_eq!(sydney.set, time("2024-06-01T03:19:00Z"));
+        assert_eq!(new_york.rise, time("2024-01-15T15:11:00Z"));
+        assert_eq!(new_york.set, time("2024-01-16T03:07:00Z"));
+    }
+
+    #[test]
+    fn moon_times_on_day_without_moonset() {
+        // The moon sets about 50 minutes later each day, so
+        // there is no moonset on one of the days of the month.
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let days: Vec<MoonTimes> = date(2023, 3, 1)
+            .iter_days()
+            .take(30)
+            .map(|day| {
+                MoonTimes::new(day, coordinates).expect("Moon times could not be calculated")
+            })
+            .collect();
+
+        assert_eq!(days.iter().filter(|times| times.set.is_none()).count(), 1);
+        assert_eq!(days.iter().filter(|times| times.rise.is_none()).count(), 1);
+    }
+
+    #[test]
+    fn moon_times_with_invalid_coordinates() {
+        let coordinates = Coordinates::new(21.4225, 190.0);
+
+        assert_eq!(
+            MoonTimes::new(date(2023, 3, 22), coordinates),
+            Err(Error::InvalidCoordinates(coordinates))
+        );
+    }
+}
@@ -9,83 +9,311 @@
 //! This module provides the main objects that are used for calculating
 //! the prayer times.
 
-use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, TimeZone, Utc};
 
 use crate::astronomy::ops;
-use crate::astronomy::solar::SolarTime;
+use crate::astronomy::solar::{SolarCoordinates, SolarTime};
 use crate::astronomy::unit::{Angle, Coordinates, Stride};
-use crate::models::method::Method;
+use crate::error::Error;
+use crate::hijri::{HijriCalendar, HijriDate};
+use crate::models::high_altitude_rule::HighLatitudeRule;
+use crate::models::method_info::TwilightModel;
+use crate::models::night_start::NightStart;
 use crate::models::parameters::Parameters;
+use crate::models::polar_resolution::PolarResolution;
 use crate::models::prayer::Prayer;
 use crate::models::rounding::Rounding;
+use crate::models::status::PrayerStatus;
+use crate::zoned::ZonedPrayerTimes;
+
+// Latitude beyond which the nearest latitude rule
+// starts to look for a latitude closer to the equator.
+const UNSAFE_LATITUDE: f64 = 65.0;
+
+// The time of a prayer along with how it was determined.
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Timing {
+    time: Option<DateTime<Utc>>,
+    status: PrayerStatus,
+}
+
+impl Timing {
+    fn new(time: Option<DateTime<Utc>>, status: PrayerStatus) -> Timing {
+        match time {
+            Some(_) => Timing { time, status },
+            None => Timing::undefined(),
+        }
+    }
+
+    fn computed(time: Option<DateTime<Utc>>) -> Timing {
+        Timing::new(time, PrayerStatus::Computed)
+    }
+
+    fn derived(time: Option<DateTime<Utc>>) -> Timing {
+        Timing::new(time, PrayerStatus::Derived)
+    }
+
+    fn undefined() -> Timing {
+        Timing {
+            time: None,
+            status: PrayerStatus::Undefined,
+        }
+    }
+
+    // Marks a defined time as derived, used when the
+    // times come from a polar resolution.
+    fn as_derived(&self) -> Timing {
+        Timing::derived(self.time)
+    }
+
+    fn shifted(&self, duration: Duration) -> Result<Timing, Error> {
+        let time = self
+            .time
+            .map(|time| PrayerTimes::offset(time, duration))
+            .transpose()?;
+
+        Ok(Timing::new(time, self.status))
+    }
+
+    fn rounded(&self, rounding: Rounding) -> Timing {
+        Timing::new(
+            self.time.map(|time| time.rounded_minute(rounding)),
+            self.status,
+        )
+    }
+}
+
+// The times for a single day, with the adjustments
+// applied, but not yet rounded.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub(crate) struct DayTimes {
+    fajr: Timing,
+    sunrise: Timing,
+    dhuhr: Timing,
+    asr: Timing,
+    maghrib: Timing,
+    isha: Timing,
+    sunset: Timing,
+}
+
+impl DayTimes {
+    fn map<F>(&self, f: F) -> Result<DayTimes, Error>
+    where
+        F: Fn(&Timing) -> Result<Timing, Error>,
+    {
+        Ok(DayTimes {
+            fajr: f(&self.fajr)?,
+            sunrise: f(&self.sunrise)?,
+            dhuhr: f(&self.dhuhr)?,
+            asr: f(&self.asr)?,
+            maghrib: f(&self.maghrib)?,
+            isha: f(&self.isha)?,
+            sunset: f(&self.sunset)?,
+        })
+    }
+}
+
+// The solar times used for calculating the prayer times of a day,
+// which may belong to a nearby day or latitude on polar days.
+struct ResolvedSolarTime {
+    solar_time: SolarTime,
+    solar_time_tomorrow: SolarTime,
+    coordinates: Coordinates,
+    date: DateTime<Utc>,
+    days_offset: i64,
+}
 
 /// A data struct to hold the timing for all
 /// prayers.
 #[derive(PartialEq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrayerTimes {
-    fajr: DateTime<Utc>,
-    sunrise: DateTime<Utc>,
-    dhuhr: DateTime<Utc>,
-    asr: DateTime<Utc>,
-    maghrib: DateTime<Utc>,
-    isha: DateTime<Utc>,
-    middle_of_the_night: DateTime<Utc>,
-    qiyam: DateTime<Utc>,
-    fajr_tomorrow: DateTime<Utc>,
+    fajr: Timing,
+    sunrise: Timing,
+    dhuhr: Timing,
+    asr: Timing,
+    maghrib: Timing,
+    isha: Timing,
+    middle_of_the_night: Timing,
+    qiyam: Timing,
+    fajr_tomorrow: Timing,
     coordinates: Coordinates,
     date: DateTime<Utc>,
     parameters: Parameters,
 }
 
 impl PrayerTimes {
-    pub fn new(date: NaiveDate, coordinates: Coordinates, parameters: Parameters) -> PrayerTimes {
-        let prayer_date = date
-            .and_hms_opt(0, 0, 0)
-            .expect("Invalid date provided")
-            .and_utc();
-        let tomorrow = prayer_date.tomorrow();
-        let solar_time = SolarTime::new(prayer_date, coordinates);
-        let solar_time_tomorrow = SolarTime::new(tomorrow, coordinates);
-
-        let asr = solar_time.afternoon(parameters.madhab.shadow().into());
-        let night = solar_time_tomorrow
-            .sunrise
-            .signed_duration_since(solar_time.sunset);
-
-        let final_fajr =
-            PrayerTimes::calculate_fajr(parameters, solar_time, night, coordinates, prayer_date)
-                .rounded_minute(parameters.rounding);
-        let final_sunrise = solar_time
-            .sunrise
-            .adjust_time(parameters.time_adjustments(Prayer::Sunrise))
-            .rounded_minute(parameters.rounding);
-        let final_dhuhr = solar_time
-            .transit
-            .adjust_time(parameters.time_adjustments(Prayer::Dhuhr))
-            .rounded_minute(parameters.rounding);
-        let final_asr = asr
-            .adjust_time(parameters.time_adjustments(Prayer::Asr))
-            .rounded_minute(parameters.rounding);
-        let final_maghrib = ops::adjust_time(
-            &solar_time.sunset,
-            parameters.time_adjustments(Prayer::Maghrib),
+    pub fn new(
+        date: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<PrayerTimes, Error> {
+        PrayerTimes::on_local_date(date, date, coordinates, parameters)
+    }
+
+    // Calculates the prayer times for the solar date, on the given
+    // date of the local calendar, which can be a day apart from it
+    // near the date line.
+    pub(crate) fn on_local_date(
+        date: NaiveDate,
+        local_date: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<PrayerTimes, Error> {
+        if !coordinates.is_valid() {
+            return Err(Error::InvalidCoordinates(coordinates));
+        }
+
+        let prayer_date = PrayerTimes::prayer_date(date)?;
+        let solar_coordinates = PrayerTimes::solar_coordinates(prayer_date);
+
+        PrayerTimes::at_location(
+            prayer_date,
+            local_date,
+            solar_coordinates,
+            coordinates,
+            parameters,
         )
-        .rounded_minute(parameters.rounding);
-        let final_isha =
-            PrayerTimes::calculate_isha(parameters, solar_time, night, coordinates, prayer_date)
-                .rounded_minute(parameters.rounding);
-
-        // Calculate the middle of the night and qiyam times
-        let (final_middle_of_night, final_qiyam, final_fajr_tomorrow) =
-            PrayerTimes::calculate_qiyam(
-                final_maghrib,
+    }
+
+    /// Calculates the prayer times for each of the locations on the
+    /// same date. The position of the sun on the date is calculated
+    /// once and shared by all the locations, and when the `rayon`
+    /// feature is enabled the locations are calculated in parallel.
+    ///
+    /// The results are in the same order as the locations.
+    pub fn for_locations(
+        date: NaiveDate,
+        locations: &[Coordinates],
+        parameters: Parameters,
+    ) -> Vec<Result<PrayerTimes, Error>> {
+        let solar_coordinates = PrayerTimes::prayer_date(date)
+            .map(|prayer_date| (prayer_date, PrayerTimes::solar_coordinates(prayer_date)));
+        let calculate = |coordinates: &Coordinates| {
+            if !coordinates.is_valid() {
+                return Err(Error::InvalidCoordinates(*coordinates));
+            }
+
+            let (prayer_date, solar_coordinates) = solar_coordinates?;
+
+            PrayerTimes::at_location(
+                prayer_date,
+                date,
+                solar_coordinates,
+                *coordinates,
                 parameters,
-                solar_time_tomorrow,
-                coordinates,
-                tomorrow,
-            );
+            )
+        };
 
-        PrayerTimes {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            locations.par_iter().map(calculate).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            locations.iter().map(calculate).collect()
+        }
+    }
+
+    fn prayer_date(date: NaiveDate) -> Result<DateTime<Utc>, Error> {
+        if !PrayerTimes::is_in_range(date) {
+            return Err(Error::DateOutOfRange);
+        }
+
+        date.and_hms_opt(0, 0, 0)
+            .map(|time| time.and_utc())
+            .ok_or(Error::DateOutOfRange)
+    }
+
+    // The solar coordinates from the day before the
+    // date up to three days after it, which do not
+    // depend on the location.
+    fn solar_coordinates(prayer_date: DateTime<Utc>) -> [SolarCoordinates; 5] {
+        let yesterday = prayer_date.yesterday();
+
+        [
+            SolarCoordinates::for_date(yesterday),
+            SolarCoordinates::for_date(prayer_date),
+            SolarCoordinates::for_date(prayer_date.tomorrow()),
+            SolarCoordinates::for_date(prayer_date.tomorrow().tomorrow()),
+            SolarCoordinates::for_date(prayer_date.tomorrow().tomorrow().tomorrow()),
+        ]
+    }
+
+    fn at_location(
+        prayer_date: DateTime<Utc>,
+        local_date: NaiveDate,
+        solar_coordinates: [SolarCoordinates; 5],
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<PrayerTimes, Error> {
+        let [before, today, tomorrow, after_tomorrow, later] = solar_coordinates;
+        let solar_time =
+            SolarTime::from_solar_coordinates(prayer_date, coordinates, before, today, tomorrow)?;
+        let solar_time_tomorrow = SolarTime::from_solar_coordinates(
+            prayer_date.tomorrow(),
+            coordinates,
+            today,
+            tomorrow,
+            after_tomorrow,
+        )?;
+        let solar_time_after_tomorrow = SolarTime::from_solar_coordinates(
+            prayer_date.tomorrow().tomorrow(),
+            coordinates,
+            tomorrow,
+            after_tomorrow,
+            later,
+        )?;
+        let today = PrayerTimes::calculate_day(
+            prayer_date,
+            local_date,
+            solar_time,
+            solar_time_tomorrow,
+            coordinates,
+            parameters,
+        )?;
+        let tomorrow = PrayerTimes::calculate_day(
+            prayer_date.tomorrow(),
+            local_date.succ_opt().ok_or(Error::DateOutOfRange)?,
+            solar_time_tomorrow,
+            solar_time_after_tomorrow,
+            coordinates,
+            parameters,
+        )?;
+
+        PrayerTimes::from_days(prayer_date, coordinates, parameters, today, tomorrow)
+    }
+
+    // Puts together the prayer times from the times of
+    // the given date and the day that follows it.
+    pub(crate) fn from_days(
+        prayer_date: DateTime<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+        today: DayTimes,
+        tomorrow: DayTimes,
+    ) -> Result<PrayerTimes, Error> {
+        let final_fajr = today.fajr.rounded(parameters.rounding);
+        let final_sunrise = today.sunrise.rounded(parameters.rounding.for_sunrise());
+        let final_dhuhr = today.dhuhr.rounded(parameters.rounding);
+        let final_asr = today.asr.rounded(parameters.rounding);
+        let final_maghrib = today.maghrib.rounded(parameters.rounding);
+        let final_isha = today.isha.rounded(parameters.rounding);
+
+        // Calculate the middle of the night and qiyam times, from
+        // the start of the night until Fajr of the next day.
+        let nightfall = match parameters.night_start {
+            NightStart::Maghrib => final_maghrib,
+            NightStart::Sunset => today.sunset.rounded(parameters.rounding),
+        };
+        let (final_middle_of_night, final_qiyam) =
+            PrayerTimes::calculate_qiyam(nightfall, tomorrow.fajr)?;
+
+        Ok(PrayerTimes {
             fajr: final_fajr,
             sunrise: final_sunrise,
             dhuhr: final_dhuhr,
@@ -94,14 +322,108 @@ impl PrayerTimes {
             isha: final_isha,
             middle_of_the_night: final_middle_of_night,
             qiyam: final_qiyam,
-            fajr_tomorrow: final_fajr_tomorrow,
+            fajr_tomorrow: tomorrow.fajr,
             coordinates: coordinates,
             date: prayer_date,
             parameters: parameters,
+        })
+    }
+
+    /// Returns the time of the prayer, or `None` when the
+    /// prayer has no time on this date and location.
+    pub fn time(&self, prayer: Prayer) -> Option<DateTime<Utc>> {
+        self.timing(prayer).time
+    }
+
+    /// Returns the time of the prayer, or [Error::SunNeverReachesAngle](enum.Error.html)
+    /// with the angle that is needed for the prayer when it has no time
+    /// on this date and location.
+    pub fn try_time(&self, prayer: Prayer) -> Result<DateTime<Utc>, Error> {
+        match self.time(prayer) {
+            Some(time) => Ok(time),
+            None => Err(Error::SunNeverReachesAngle {
+                prayer,
+                angle: self.required_angle(prayer)?.degrees,
+            }),
         }
     }
 
-    pub fn time(&self, prayer: Prayer) -> DateTime<Utc> {
+    /// Returns how the time of the prayer was determined.
+    pub fn status(&self, prayer: Prayer) -> PrayerStatus {
+        self.timing(prayer).status
+    }
+
+    /// Returns the Hijri date of the date of the prayer times, i.e.
+    /// the date of the daytime prayers.
+    pub fn hijri_date(&self, calendar: HijriCalendar) -> HijriDate {
+        HijriDate::from_gregorian(self.date.date_naive(), calendar)
+    }
+
+    /// Returns the middle of the night, from Maghrib, or sunset as
+    /// set by the `night_start` of the parameters, until Fajr of
+    /// the next day.
+    pub fn middle_of_the_night(&self) -> Option<DateTime<Utc>> {
+        self.middle_of_the_night.time
+    }
+
+    /// Returns the current prayer, or [Error::BeforeFajr](enum.Error.html)
+    /// when the current time is before today's Fajr.
+    pub fn current(&self) -> Result<Prayer, Error> {
+        self.current_at(Utc::now())
+    }
+
+    /// Returns the prayer that is current at the given time, or
+    /// [Error::BeforeFajr](enum.Error.html) when the time is
+    /// before today's Fajr.
+    pub fn current_at(&self, time: DateTime<Utc>) -> Result<Prayer, Error> {
+        self.current_time(time).ok_or(Error::BeforeFajr)
+    }
+
+    pub fn next(&self) -> Result<Prayer, Error> {
+        self.next_at(Utc::now())
+    }
+
+    /// Returns the prayer that follows the current prayer at
+    /// the given time.
+    pub fn next_at(&self, time: DateTime<Utc>) -> Result<Prayer, Error> {
+        let order = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+            Prayer::Qiyam,
+            Prayer::FajrTomorrow,
+        ];
+        let current = self.current_at(time)?;
+        let next = order
+            .iter()
+            .skip_while(|prayer| **prayer != current)
+            .skip(1)
+            .find(|prayer| self.time(**prayer).is_some())
+            .copied()
+            .unwrap_or(Prayer::FajrTomorrow);
+
+        Ok(next)
+    }
+
+    /// Returns the time remaining until the next prayer,
+    /// to the second.
+    pub fn time_remaining(&self) -> Result<Duration, Error> {
+        self.time_remaining_at(Utc::now())
+    }
+
+    /// Returns the time remaining from the given time until
+    /// the next prayer, to the second.
+    pub fn time_remaining_at(&self, time: DateTime<Utc>) -> Result<Duration, Error> {
+        let next_time = self.try_time(self.next_at(time)?)?;
+        let remaining = next_time.signed_duration_since(time);
+
+        Ok(Duration::seconds(remaining.num_seconds()))
+    }
+
+    fn timing(&self, prayer: Prayer) -> Timing {
         match prayer {
             Prayer::Fajr => self.fajr,
             Prayer::Sunrise => self.sunrise,
@@ -114,196 +436,449 @@ impl PrayerTimes {
         }
     }
 
-    pub fn current(&self) -> Prayer {
-        self.current_time(Utc::now()).expect("Out of bounds")
+    // The angle of the sun that is needed for the time of the prayer.
+    fn required_angle(&self, prayer: Prayer) -> Result<Angle, Error> {
+        // The sun rises and sets at this angle, which accounts
+        // for the refraction and the radius of the sun.
+        let horizon = Angle::new(-50.0 / 60.0);
+        let maghrib = if self.parameters.maghrib_angle > 0.0 {
+            Angle::new(-self.parameters.maghrib_angle)
+        } else {
+            horizon
+        };
+
+        let angle = match prayer {
+            Prayer::Fajr | Prayer::FajrTomorrow => Angle::new(-self.parameters.fajr_angle),
+            Prayer::Sunrise | Prayer::Dhuhr => horizon,
+            Prayer::Asr => {
+                let shadow = self.parameters.asr_shadow();
+                let angle = SolarTime::new(self.date, self.coordinates)?.afternoon_angle(shadow);
+
+                // There is no shadow when the sun does not rise.
+                if angle.degrees > 0.0 { angle } else { horizon }
+            }
+            Prayer::Maghrib => maghrib,
+            Prayer::Isha if self.parameters.isha_interval > 0 => maghrib,
+            Prayer::Isha => Angle::new(-self.parameters.isha_angle),
+            Prayer::Qiyam if self.maghrib.time.is_none() => maghrib,
+            Prayer::Qiyam => Angle::new(-self.parameters.fajr_angle),
+        };
+
+        Ok(angle)
+    }
+
+    fn current_time(&self, time: DateTime<Utc>) -> Option<Prayer> {
+        let order = [
+            Prayer::FajrTomorrow,
+            Prayer::Qiyam,
+            Prayer::Isha,
+            Prayer::Maghrib,
+            Prayer::Asr,
+            Prayer::Dhuhr,
+            Prayer::Sunrise,
+            Prayer::Fajr,
+        ];
+
+        order
+            .iter()
+            .copied()
+            .find(|prayer| match self.time(*prayer) {
+                Some(prayer_time) => prayer_time.signed_duration_since(time).num_seconds() <= 0,
+                None => false,
+            })
+    }
+
+    // The calculations reach from the day before the given date
+    // up to three days after it.
+    pub(crate) fn is_in_range(date: NaiveDate) -> bool {
+        date.pred_opt().is_some() && date.checked_add_days(Days::new(3)).is_some()
     }
 
-    pub fn next(&self) -> Prayer {
-        match self.current() {
-            Prayer::Fajr => Prayer::Sunrise,
-            Prayer::Sunrise => Prayer::Dhuhr,
-            Prayer::Dhuhr => Prayer::Asr,
-            Prayer::Asr => Prayer::Maghrib,
-            Prayer::Maghrib => Prayer::Isha,
-            Prayer::Isha => Prayer::Qiyam,
-            Prayer::Qiyam => Prayer::FajrTomorrow,
-            _ => Prayer::FajrTomorrow,
+    pub(crate) fn calculate_day(
+        date: DateTime<Utc>,
+        local_date: NaiveDate,
+        solar_time: SolarTime,
+        solar_time_tomorrow: SolarTime,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<DayTimes, Error> {
+        // The interval of Isha depends on the Hijri month of the evening
+        // of the local date, rather than of the solar date or of the day
+        // that a polar resolution borrows from.
+        let parameters = Parameters {
+            isha_interval: parameters.isha_interval_on(local_date),
+            ..parameters
+        };
+
+        if PrayerTimes::is_complete(solar_time, solar_time_tomorrow, parameters) {
+            return PrayerTimes::calculate_times(
+                parameters,
+                solar_time,
+                solar_time_tomorrow,
+                coordinates,
+                date,
+            );
+        } else {
+            // Nothing to do.
+        }
+
+        let resolved = match parameters.polar_resolution {
+            PolarResolution::AqrabYaum => PrayerTimes::nearest_day(date, coordinates, parameters)?,
+            PolarResolution::AqrabBalad => {
+                PrayerTimes::nearest_latitude(date, coordinates, parameters)?
+            }
+            PolarResolution::Unresolved => None,
+        };
+
+        match resolved {
+            Some(resolved) => {
+                let duration = Duration::days(-resolved.days_offset);
+
+                PrayerTimes::calculate_times(
+                    parameters,
+                    resolved.solar_time,
+                    resolved.solar_time_tomorrow,
+                    resolved.coordinates,
+                    resolved.date,
+                )?
+                .map(|timing| timing.shifted(duration).map(|timing| timing.as_derived()))
+            }
+            None => PrayerTimes::calculate_times(
+                parameters,
+                solar_time,
+                solar_time_tomorrow,
+                coordinates,
+                date,
+            ),
         }
     }
 
-    pub fn time_remaining(&self) -> (u32, u32) {
-        let next_time = self.time(self.next());
-        let now = Utc::now();
-        let now_to_next = next_time.signed_duration_since(now).num_seconds() as f64;
-        let whole: f64 = now_to_next / 60.0 / 60.0;
-        let fract = whole.fract();
-        let hours = whole.trunc() as u32;
-        let minutes = (fract * 60.0).round() as u32;
+    // Whether the sun rises and sets on both days and
+    // gets high enough in the sky for Asr.
+    fn is_complete(
+        solar_time: SolarTime,
+        solar_time_tomorrow: SolarTime,
+        parameters: Parameters,
+    ) -> bool {
+        solar_time.has_sunrise_and_sunset()
+            && solar_time_tomorrow.has_sunrise_and_sunset()
+            && solar_time.afternoon(parameters.asr_shadow()).is_some()
+    }
+
+    // Looks for the nearest day, before or after the given
+    // date, on which the sun both rises and sets.
+    fn nearest_day(
+        date: DateTime<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<Option<ResolvedSolarTime>, Error> {
+        for days in 1..=183 {
+            for days_offset in [days, -days] {
+                let resolved_date = match date.checked_add_signed(Duration::days(days_offset)) {
+                    Some(resolved_date) if PrayerTimes::is_in_range(resolved_date.date_naive()) => {
+                        resolved_date
+                    }
+                    _ => continue,
+                };
+                let solar_time = SolarTime::new(resolved_date, coordinates)?;
+                let solar_time_tomorrow = SolarTime::new(resolved_date.tomorrow(), coordinates)?;
 
-        (hours, minutes)
+                if PrayerTimes::is_complete(solar_time, solar_time_tomorrow, parameters) {
+                    return Ok(Some(ResolvedSolarTime {
+                        solar_time,
+                        solar_time_tomorrow,
+                        coordinates,
+                        date: resolved_date,
+                        days_offset,
+                    }));
+                } else {
+                    // Nothing to do.
+                }
+            }
+        }
+
+        Ok(None)
     }
 
-    fn current_time(&self, time: DateTime<Utc>) -> Option<Prayer> {
-        let current_prayer: Option<Prayer>;
-
-        if self.fajr_tomorrow.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::FajrTomorrow)
-        } else if self.qiyam.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Qiyam)
-        } else if self.isha.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Isha);
-        } else if self.maghrib.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Maghrib);
-        } else if self.asr.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Asr);
-        } else if self.dhuhr.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Dhuhr);
-        } else if self.sunrise.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Sunrise);
-        } else if self.fajr.signed_duration_since(time).num_seconds() <= 0 {
-            current_prayer = Some(Prayer::Fajr);
-        } else {
-            current_prayer = None;
+    // Looks for the nearest latitude, closer to the equator,
+    // at which the sun both rises and sets.
+    fn nearest_latitude(
+        date: DateTime<Utc>,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<Option<ResolvedSolarTime>, Error> {
+        let mut latitude = coordinates.latitude;
+
+        while latitude.abs() >= UNSAFE_LATITUDE {
+            latitude -= latitude.signum() * 0.5;
+
+            let resolved_coordinates = Coordinates::new(latitude, coordinates.longitude);
+            let solar_time = SolarTime::new(date, resolved_coordinates)?;
+            let solar_time_tomorrow = SolarTime::new(date.tomorrow(), resolved_coordinates)?;
+
+            if PrayerTimes::is_complete(solar_time, solar_time_tomorrow, parameters) {
+                return Ok(Some(ResolvedSolarTime {
+                    solar_time,
+                    solar_time_tomorrow,
+                    coordinates: resolved_coordinates,
+                    date,
+                    days_offset: 0,
+                }));
+            } else {
+                // Nothing to do.
+            }
         }
 
-        current_prayer
+        Ok(None)
     }
 
-    fn calculate_fajr(
+    fn calculate_times(
         parameters: Parameters,
         solar_time: SolarTime,
-        night: Duration,
+        solar_time_tomorrow: SolarTime,
         coordinates: Coordinates,
         prayer_date: DateTime<Utc>,
-    ) -> DateTime<Utc> {
-        let mut fajr = solar_time.time_for_solar_angle(Angle::new(-parameters.fajr_angle), false);
+    ) -> Result<DayTimes, Error> {
+        let night = match (solar_time.sunset, solar_time_tomorrow.sunrise) {
+            (Some(sunset), Some(sunrise)) => Some(sunrise.signed_duration_since(sunset)),
+            _ => None,
+        };
 
+        let fajr =
+            PrayerTimes::calculate_fajr(parameters, solar_time, night, coordinates, prayer_date)?;
+        let sunrise = Timing::computed(solar_time.sunrise);
+        let dhuhr = Timing::computed(Some(solar_time.transit));
+        let asr = Timing::computed(solar_time.afternoon(parameters.asr_shadow()));
+        let maghrib = PrayerTimes::calculate_maghrib(parameters, solar_time, night)?;
+        let isha = PrayerTimes::calculate_isha(
+            parameters,
+            solar_time,
+            maghrib,
+            night,
+            coordinates,
+            prayer_date,
+        )?;
+
+        Ok(DayTimes {
+            fajr: PrayerTimes::adjusted(fajr, parameters.time_adjustments(Prayer::Fajr))?,
+            sunrise: PrayerTimes::adjusted(sunrise, parameters.time_adjustments(Prayer::Sunrise))?,
+            dhuhr: PrayerTimes::adjusted(dhuhr, parameters.time_adjustments(Prayer::Dhuhr))?,
+            asr: PrayerTimes::adjusted(asr, parameters.time_adjustments(Prayer::Asr))?,
+            maghrib: PrayerTimes::adjusted(maghrib, parameters.time_adjustments(Prayer::Maghrib))?,
+            isha: PrayerTimes::adjusted(isha, parameters.time_adjustments(Prayer::Isha))?,
+            sunset: Timing::computed(solar_time.sunset),
+        })
+    }
+
+    fn calculate_fajr(
+        parameters: Parameters,
+        solar_time: SolarTime,
+        night: Option<Duration>,
+        coordinates: Coordinates,
+        prayer_date: DateTime<Utc>,
+    ) -> Result<Timing, Error> {
         // special case for moonsighting committee above latitude 55
-        if parameters.method == Method::MoonsightingCommittee && coordinates.latitude >= 55.0 {
-            let night_fraction = night.num_seconds() / 7;
-            fajr = solar_time
-                .sunrise
-                .checked_add_signed(Duration::seconds(-night_fraction))
-                .unwrap();
-        } else {
-            // Nothing to do.
-        }
+        let fajr = if parameters.method.twilight() == TwilightModel::Seasonal
+            && coordinates.latitude >= 55.0
+        {
+            match (solar_time.sunrise, night) {
+                (Some(sunrise), Some(night)) => {
+                    let night_fraction = night.num_seconds() / 7;
 
-        let safe_fajr = if parameters.method == Method::MoonsightingCommittee {
-            let day_of_year = prayer_date.ordinal();
-            ops::season_adjusted_morning_twilight(
-                coordinates.latitude,
-                day_of_year,
-                prayer_date.year() as u32,
-                solar_time.sunrise,
-            )
+                    Timing::derived(Some(PrayerTimes::offset(
+                        sunrise,
+                        Duration::seconds(-night_fraction),
+                    )?))
+                }
+                _ => Timing::undefined(),
+            }
         } else {
-            let portion = parameters.night_portions().0;
-            let night_fraction = portion * (night.num_seconds() as f64);
+            Timing::computed(
+                solar_time.time_for_solar_angle(Angle::new(-parameters.fajr_angle), false),
+            )
+        };
 
-            solar_time
-                .sunrise
-                .checked_add_signed(Duration::seconds(-night_fraction as i64))
-                .unwrap()
+        let safe_fajr = match (solar_time.sunrise, night) {
+            (Some(sunrise), _) if parameters.method.twilight() == TwilightModel::Seasonal => {
+                let day_of_year = prayer_date.ordinal();
+
+                Timing::computed(Some(ops::season_adjusted_morning_twilight(
+                    coordinates.latitude,
+                    day_of_year,
+                    prayer_date.year() as u32,
+                    sunrise,
+                )))
+            }
+            (Some(sunrise), Some(night)) => {
+                let portion = parameters.night_portions().0;
+                let night_fraction = portion * (night.num_seconds() as f64);
+
+                Timing::derived(Some(PrayerTimes::offset(
+                    sunrise,
+                    Duration::seconds(-night_fraction as i64),
+                )?))
+            }
+            _ => Timing::undefined(),
         };
 
-        if fajr < safe_fajr {
-            fajr = safe_fajr;
-        } else {
-            // Nothing to do.
+        match (fajr.time, safe_fajr.time) {
+            (Some(time), Some(safe_time)) if time < safe_time => Ok(safe_fajr),
+            (Some(_), _) => Ok(fajr),
+            (None, _) => Ok(safe_fajr.as_derived()),
         }
+    }
+
+    fn calculate_maghrib(
+        parameters: Parameters,
+        solar_time: SolarTime,
+        night: Option<Duration>,
+    ) -> Result<Timing, Error> {
+        let sunset = match solar_time.sunset {
+            Some(sunset) => sunset,
+            None => return Ok(Timing::undefined()),
+        };
+
+        if parameters.maghrib_angle > 0.0 {
+            let maghrib = Timing::computed(
+                solar_time.time_for_solar_angle(Angle::new(-parameters.maghrib_angle), true),
+            );
+
+            // Same safety clamping as Isha, except that the twilight
+            // angle rule uses the Maghrib angle for its night portion.
+            let safe_maghrib = match night {
+                Some(night) => {
+                    let portion = match parameters.high_latitude_rule {
+                        HighLatitudeRule::TwilightAngle => parameters.maghrib_angle / 60.0,
+                        _ => parameters.night_portions().1,
+                    };
+                    let night_fraction = portion * (night.num_seconds() as f64);
+
+                    Timing::derived(Some(PrayerTimes::offset(
+                        sunset,
+                        Duration::seconds(night_fraction as i64),
+                    )?))
+                }
+                None => Timing::undefined(),
+            };
 
-        fajr.adjust_time(parameters.time_adjustments(Prayer::Fajr))
+            match (maghrib.time, safe_maghrib.time) {
+                (Some(time), Some(safe_time)) if time > safe_time => Ok(safe_maghrib),
+                (Some(_), _) => Ok(maghrib),
+                (None, _) => Ok(safe_maghrib),
+            }
+        } else {
+            Ok(Timing::computed(Some(sunset)))
+        }
     }
 
     fn calculate_isha(
         parameters: Parameters,
         solar_time: SolarTime,
-        night: Duration,
+        maghrib: Timing,
+        night: Option<Duration>,
         coordinates: Coordinates,
         prayer_date: DateTime<Utc>,
-    ) -> DateTime<Utc> {
-        let mut isha: DateTime<Utc>;
-
+    ) -> Result<Timing, Error> {
         if parameters.isha_interval > 0 {
-            isha = solar_time
-                .sunset
-                .checked_add_signed(Duration::seconds((parameters.isha_interval * 60) as i64))
-                .unwrap();
+            return PrayerTimes::adjusted(maghrib, parameters.isha_interval as i64);
         } else {
-            isha = solar_time.time_for_solar_angle(Angle::new(-parameters.isha_angle), true);
-
-            // special case for moonsighting committee above latitude 55
-            if parameters.method == Method::MoonsightingCommittee && coordinates.latitude >= 55.0 {
-                let night_fraction = night.num_seconds() / 7;
-                isha = solar_time
-                    .sunset
-                    .checked_add_signed(Duration::seconds(night_fraction))
-                    .unwrap();
-            } else {
-                // Nothing to do.
+            // Nothing to do.
+        }
+
+        // special case for moonsighting committee above latitude 55
+        let isha = if parameters.method.twilight() == TwilightModel::Seasonal
+            && coordinates.latitude >= 55.0
+        {
+            match (solar_time.sunset, night) {
+                (Some(sunset), Some(night)) => {
+                    let night_fraction = night.num_seconds() / 7;
+
+                    Timing::derived(Some(PrayerTimes::offset(
+                        sunset,
+                        Duration::seconds(night_fraction),
+                    )?))
+                }
+                _ => Timing::undefined(),
             }
+        } else {
+            Timing::computed(
+                solar_time.time_for_solar_angle(Angle::new(-parameters.isha_angle), true),
+            )
+        };
 
-            let safe_isha = if parameters.method == Method::MoonsightingCommittee {
+        let safe_isha = match (solar_time.sunset, night) {
+            (Some(sunset), _) if parameters.method.twilight() == TwilightModel::Seasonal => {
                 let day_of_year = prayer_date.ordinal();
 
-                ops::season_adjusted_evening_twilight(
+                Timing::computed(Some(ops::season_adjusted_evening_twilight(
                     coordinates.latitude,
                     day_of_year,
                     prayer_date.year() as u32,
-                    solar_time.sunset,
+                    sunset,
                     parameters.shafaq,
-                )
-            } else {
+                )))
+            }
+            (Some(sunset), Some(night)) => {
                 let portion = parameters.night_portions().1;
                 let night_fraction = portion * (night.num_seconds() as f64);
 
-                solar_time
-                    .sunset
-                    .checked_add_signed(Duration::seconds(night_fraction as i64))
-                    .unwrap()
-            };
-
-            if isha > safe_isha {
-                isha = safe_isha;
-            } else {
-                // Nothing to do.
+                Timing::derived(Some(PrayerTimes::offset(
+                    sunset,
+                    Duration::seconds(night_fraction as i64),
+                )?))
             }
-        }
+            _ => Timing::undefined(),
+        };
 
-        isha.adjust_time(parameters.time_adjustments(Prayer::Isha))
+        match (isha.time, safe_isha.time) {
+            (Some(time), Some(safe_time)) if time > safe_time => Ok(safe_isha),
+            (Some(_), _) => Ok(isha),
+            (None, _) => Ok(safe_isha.as_derived()),
+        }
     }
 
     fn calculate_qiyam(
-        current_maghrib: DateTime<Utc>,
-        parameters: Parameters,
-        solar_time: SolarTime,
-        coordinates: Coordinates,
-        prayer_date: DateTime<Utc>,
-    ) -> (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>) {
-        let tomorrow = prayer_date.tomorrow();
-        let solar_time_tomorrow = SolarTime::new(tomorrow, coordinates);
-        let night = solar_time_tomorrow
-            .sunrise
-            .signed_duration_since(solar_time.sunset);
-
-        let tomorrow_fajr =
-            PrayerTimes::calculate_fajr(parameters, solar_time, night, coordinates, prayer_date);
-        let night_duration = tomorrow_fajr
-            .signed_duration_since(current_maghrib)
-            .num_seconds() as f64;
+        nightfall: Timing,
+        tomorrow_fajr: Timing,
+    ) -> Result<(Timing, Timing), Error> {
+        let (maghrib, fajr) = match (nightfall.time, tomorrow_fajr.time) {
+            (Some(maghrib), Some(fajr)) => (maghrib, fajr),
+            _ => return Ok((Timing::undefined(), Timing::undefined())),
+        };
+        let status = if nightfall.status == PrayerStatus::Computed
+            && tomorrow_fajr.status == PrayerStatus::Computed
+        {
+            PrayerStatus::Computed
+        } else {
+            PrayerStatus::Derived
+        };
+
+        let night_duration = fajr.signed_duration_since(maghrib).num_seconds() as f64;
         let middle_night_portion = (night_duration / 2.0) as i64;
         let last_third_portion = (night_duration * (2.0 / 3.0)) as i64;
-        let middle_of_night = current_maghrib
-            .checked_add_signed(Duration::seconds(middle_night_portion))
-            .unwrap()
-            .rounded_minute(Rounding::Nearest);
-        let last_third_of_night = current_maghrib
-            .checked_add_signed(Duration::seconds(last_third_portion))
-            .unwrap()
-            .rounded_minute(Rounding::Nearest);
+        let middle_of_night =
+            PrayerTimes::offset(maghrib, Duration::seconds(middle_night_portion))?
+                .rounded_minute(Rounding::Nearest);
+        let last_third_of_night =
+            PrayerTimes::offset(maghrib, Duration::seconds(last_third_portion))?
+                .rounded_minute(Rounding::Nearest);
+
+        Ok((
+            Timing::new(Some(middle_of_night), status),
+            Timing::new(Some(last_third_of_night), status),
+        ))
+    }
+
+    // Applies an adjustment, in minutes, to the given time.
+    fn adjusted(timing: Timing, minutes: i64) -> Result<Timing, Error> {
+        let duration = Duration::try_minutes(minutes).ok_or(Error::DateOutOfRange)?;
 
-        (middle_of_night, last_third_of_night, tomorrow_fajr)
+        timing.shifted(duration)
+    }
+
+    fn offset(time: DateTime<Utc>, duration: Duration) -> Result<DateTime<Utc>, Error> {
+        time.checked_add_signed(duration)
+            .ok_or(Error::DateOutOfRange)
     }
 }
 
@@ -338,17 +913,32 @@ impl PrayerSchedule {
         self
     }
 
-    pub fn calculate(&self) -> Result<PrayerTimes, String> {
-        if self.date.is_some() && self.coordinates.is_some() && self.params.is_some() {
-            Ok(PrayerTimes::new(
-                self.date.unwrap(),
-                self.coordinates.unwrap(),
-                self.params.unwrap(),
-            ))
-        } else {
-            Err(String::from(
-                "Required information is needed in order to calculate the prayer times.",
-            ))
+    pub fn calculate(&self) -> Result<PrayerTimes, Error> {
+        let date = self.date.ok_or(Error::MissingDate)?;
+        let coordinates = self.coordinates.ok_or(Error::MissingCoordinates)?;
+        let params = self.validated_params()?;
+
+        PrayerTimes::new(date, coordinates, params)
+    }
+
+    /// Calculates the prayer times for the date on the
+    /// calendar of the given time zone.
+    pub fn calculate_in<Tz: TimeZone>(&self, timezone: Tz) -> Result<ZonedPrayerTimes<Tz>, Error> {
+        let date = self.date.ok_or(Error::MissingDate)?;
+        let coordinates = self.coordinates.ok_or(Error::MissingCoordinates)?;
+        let params = self.validated_params()?;
+
+        ZonedPrayerTimes::new(date, coordinates, params, timezone)
+    }
+
+    // The parameters, as long as their validation
+    // did not find any errors.
+    fn validated_params(&self) -> Result<Parameters, Error> {
+        let params = self.params.ok_or(Error::MissingParameters)?;
+
+        match params.validate().into_iter().find(|issue| issue.is_error()) {
+            Some(issue) => Err(Error::InvalidParameters(issue)),
+            None => Ok(params),
         }
     }
 }
@@ -356,8 +946,11 @@ impl PrayerSchedule {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::madhab::Madhab;
     use crate::Configuration;
+    use crate::hijri::month::HijriMonth;
+    use crate::models::madhab::Madhab;
+    use crate::models::method::Method;
+    use crate::models::validation::ParameterIssue;
     use chrono::{NaiveDate, TimeZone, Utc};
 
     #[test]
@@ -366,7 +959,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = local_date.and_hms_opt(9, 0, 0).unwrap().and_utc();
 
         assert_eq!(times.current_time(current_prayer_time), Some(Prayer::Fajr));
@@ -378,7 +972,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = local_date.and_hms_opt(11, 0, 0).unwrap().and_utc();
 
         assert_eq!(
@@ -393,7 +988,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = local_date.and_hms_opt(19, 0, 0).unwrap().and_utc();
 
         assert_eq!(times.current_time(current_prayer_time), Some(Prayer::Dhuhr));
@@ -405,7 +1001,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = local_date.and_hms_opt(22, 26, 0).unwrap().and_utc();
 
         assert_eq!(times.current_time(current_prayer_time), Some(Prayer::Asr));
@@ -417,7 +1014,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid data provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = Utc.with_ymd_and_hms(2015, 7, 13, 01, 0, 0).unwrap();
 
         assert_eq!(
@@ -432,7 +1030,8 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = Utc.with_ymd_and_hms(2015, 7, 13, 02, 0, 0).unwrap();
 
         assert_eq!(times.current_time(current_prayer_time), Some(Prayer::Isha));
@@ -443,12 +1042,46 @@ mod tests {
         let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid data provided");
         let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
         let coordinates = Coordinates::new(35.7750, -78.6336);
-        let times = PrayerTimes::new(local_date, coordinates, params);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
         let current_prayer_time = local_date.and_hms_opt(8, 0, 0).unwrap().and_utc();
 
         assert_eq!(times.current_time(current_prayer_time), None);
     }
 
+    #[test]
+    fn current_and_next_prayer_at_given_time() {
+        // Given the below DateTime, Fajr is at 2015-07-12T08:42:00Z
+        // and sunrise is at 2015-07-12T10:08:00Z
+        let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let time = local_date.and_hms_opt(9, 0, 30).unwrap().and_utc();
+
+        assert_eq!(times.current_at(time), Ok(Prayer::Fajr));
+        assert_eq!(times.next_at(time), Ok(Prayer::Sunrise));
+        assert_eq!(
+            times.time_remaining_at(time),
+            Ok(Duration::hours(1) + Duration::minutes(7) + Duration::seconds(30))
+        );
+    }
+
+    #[test]
+    fn current_and_next_prayer_before_fajr() {
+        let local_date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let times = PrayerTimes::new(local_date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let time = local_date.and_hms_opt(8, 0, 0).unwrap().and_utc();
+
+        assert_eq!(times.current_at(time), Err(Error::BeforeFajr));
+        assert_eq!(times.next_at(time), Err(Error::BeforeFajr));
+        assert_eq!(times.time_remaining_at(time), Err(Error::BeforeFajr));
+    }
+
     #[test]
     fn calculate_times_for_moonsighting_method() {
         let date = NaiveDate::from_ymd_opt(2016, 1, 31).expect("Invalid date provided");
@@ -469,33 +1102,51 @@ mod tests {
                 // maghrib = 2016-01-31 22:43:00 UTC
                 // isha    = 2016-02-01 00:05:00 UTC
                 assert_eq!(
-                    schedule.time(Prayer::Fajr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Fajr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "10:48 AM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Sunrise)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "12:16 PM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Dhuhr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Dhuhr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "5:33 PM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Asr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Asr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "8:20 PM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Maghrib)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "10:43 PM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Isha).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Isha)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "12:05 AM"
                 );
             }
@@ -524,33 +1175,51 @@ mod tests {
                 // maghrib = 2016-01-01 14:25:00 UTC
                 // isha    = 2016-01-01 16:02:00 UTC
                 assert_eq!(
-                    schedule.time(Prayer::Fajr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Fajr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "6:34 AM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Sunrise)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "8:19 AM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Dhuhr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Dhuhr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "11:25 AM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Asr).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Asr)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "12:36 PM"
                 );
                 assert_eq!(
                     schedule
                         .time(Prayer::Maghrib)
+                        .unwrap()
                         .format("%-l:%M %p")
                         .to_string(),
                     "2:25 PM"
                 );
                 assert_eq!(
-                    schedule.time(Prayer::Isha).format("%-l:%M %p").to_string(),
+                    schedule
+                        .time(Prayer::Isha)
+                        .unwrap()
+                        .format("%-l:%M %p")
+                        .to_string(),
                     "4:02 PM"
                 );
             }
@@ -558,4 +1227,586 @@ mod tests {
             Err(_err) => assert!(false),
         }
     }
+    #[test]
+    fn calculate_maghrib_using_maghrib_angle() {
+        let date = NaiveDate::from_ymd_opt(2022, 3, 21).expect("Invalid date provided");
+        let params = Configuration::with(Method::Tehran, Madhab::Shafi);
+        let coordinates = Coordinates::new(35.6892, 51.3890);
+        let schedule = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let sunset = SolarTime::new(schedule.date, coordinates)
+            .expect("Solar time could not be calculated")
+            .sunset
+            .unwrap();
+
+        // sunset  = 2022-03-21 14:47:00 UTC
+        // maghrib = 2022-03-21 15:05:00 UTC
+        assert_eq!(sunset.format("%-l:%M %p").to_string(), "2:47 PM");
+        assert_eq!(
+            schedule
+                .time(Prayer::Maghrib)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "3:05 PM"
+        );
+        assert!(schedule.time(Prayer::Isha).unwrap() > schedule.time(Prayer::Maghrib).unwrap());
+    }
+
+    #[test]
+    fn calculate_maghrib_angle_with_high_latitude_clamping() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let params = Configuration::new(10.0, 10.0)
+            .maghrib_angle(6.0)
+            .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .done();
+        let coordinates = Coordinates::new(55.6761, 12.5683);
+        let schedule = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        // sunset  = 2022-06-21 19:58:00 UTC
+        // The sun reaches 6° below the horizon after the first seventh
+        // of the night, so Maghrib is clamped to the same time as Isha.
+        assert_eq!(
+            schedule
+                .time(Prayer::Maghrib)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "8:53 PM"
+        );
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "8:53 PM"
+        );
+    }
+
+    #[test]
+    fn calculate_isha_interval_from_maghrib_angle() {
+        let date = NaiveDate::from_ymd_opt(2022, 3, 21).expect("Invalid date provided");
+        let params = Configuration::new(17.7, 0.0)
+            .maghrib_angle(4.5)
+            .isha_interval(90)
+            .done();
+        let coordinates = Coordinates::new(35.6892, 51.3890);
+        let schedule = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(
+            schedule
+                .time(Prayer::Isha)
+                .unwrap()
+                .signed_duration_since(schedule.time(Prayer::Maghrib).unwrap())
+                .num_minutes(),
+            90
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_missing_information() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+
+        assert_eq!(
+            PrayerSchedule::new()
+                .for_location(coordinates)
+                .with_configuration(params)
+                .calculate(),
+            Err(Error::MissingDate)
+        );
+        assert_eq!(
+            PrayerSchedule::new()
+                .on(date)
+                .with_configuration(params)
+                .calculate(),
+            Err(Error::MissingCoordinates)
+        );
+        assert_eq!(
+            PrayerSchedule::new()
+                .on(date)
+                .for_location(coordinates)
+                .calculate(),
+            Err(Error::MissingParameters)
+        );
+    }
+
+    #[test]
+    fn middle_of_the_night_for_jafari_method() {
+        let date = NaiveDate::from_ymd_opt(2023, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(34.6401, 50.8764);
+        let jafari = PrayerTimes::new(date, coordinates, Method::Jafari.parameters())
+            .expect("Prayer times could not be calculated");
+        let tehran = PrayerTimes::new(date, coordinates, Method::Tehran.parameters())
+            .expect("Prayer times could not be calculated");
+        let solar_time = SolarTime::new(date.and_hms_opt(0, 0, 0).unwrap().and_utc(), coordinates)
+            .expect("Solar time could not be calculated");
+        let sunset = solar_time.sunset.unwrap().rounded_minute(Rounding::Nearest);
+        let fajr = jafari.time(Prayer::FajrTomorrow).unwrap();
+
+        // The night of the Jafari method starts at sunset, rather than at
+        // Maghrib like the other methods.
+        assert_eq!(
+            jafari.middle_of_the_night(),
+            Some(sunset + (fajr - sunset) / 2).map(|time| time.rounded_minute(Rounding::Nearest))
+        );
+        assert_eq!(
+            tehran.middle_of_the_night(),
+            Some(
+                tehran.time(Prayer::Maghrib).unwrap()
+                    + (tehran.time(Prayer::FajrTomorrow).unwrap()
+                        - tehran.time(Prayer::Maghrib).unwrap())
+                        / 2
+            )
+            .map(|time| time.rounded_minute(Rounding::Nearest))
+        );
+        assert!(jafari.time(Prayer::Maghrib).unwrap() > sunset);
+    }
+
+    #[test]
+    fn jafari_method_uses_shafi_shadow() {
+        let date = NaiveDate::from_ymd_opt(2023, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(34.6401, 50.8764);
+        let asr = |params| {
+            PrayerTimes::new(date, coordinates, params)
+                .expect("Prayer times could not be calculated")
+                .time(Prayer::Asr)
+        };
+        let shafi = asr(Method::Jafari.parameters());
+
+        assert_eq!(
+            asr(Configuration::with(Method::Jafari, Madhab::Hanafi)),
+            shafi
+        );
+        assert_eq!(
+            asr(Configuration::from(Method::Jafari.parameters())
+                .madhab(Madhab::Hanafi)
+                .done()),
+            shafi
+        );
+        assert!(
+            asr(Configuration::from(Method::Jafari.parameters())
+                .method(Method::Other)
+                .madhab(Madhab::Hanafi)
+                .done())
+                > shafi
+        );
+    }
+
+    #[test]
+    fn night_start_of_custom_method() {
+        let date = NaiveDate::from_ymd_opt(2023, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(34.6401, 50.8764);
+        let jafari = Method::Jafari.parameters();
+        let custom = Configuration::from(jafari).method(Method::Other).done();
+        let middle_of_the_night = |params| {
+            PrayerTimes::new(date, coordinates, params)
+                .expect("Prayer times could not be calculated")
+                .middle_of_the_night()
+        };
+
+        assert_eq!(custom.night_start, NightStart::Sunset);
+        assert_eq!(middle_of_the_night(custom), middle_of_the_night(jafari));
+        assert!(
+            middle_of_the_night(
+                Configuration::from(custom)
+                    .night_start(NightStart::Maghrib)
+                    .done()
+            ) > middle_of_the_night(jafari)
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_invalid_parameters() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::new(18.0, 0.0).done();
+
+        assert_eq!(
+            PrayerSchedule::new()
+                .on(date)
+                .for_location(coordinates)
+                .with_configuration(params)
+                .calculate(),
+            Err(Error::InvalidParameters(ParameterIssue::MissingAngle(
+                Prayer::Isha
+            )))
+        );
+
+        // Warnings do not keep the prayer times from being calculated.
+        let mut params = Method::UmmAlQura.parameters();
+        params.isha_angle = 17.0;
+
+        assert!(
+            PrayerSchedule::new()
+                .on(date)
+                .for_location(coordinates)
+                .with_configuration(params)
+                .calculate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_invalid_coordinates() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(95.0, -78.6336);
+
+        assert_eq!(
+            PrayerTimes::new(date, coordinates, params),
+            Err(Error::InvalidCoordinates(coordinates))
+        );
+    }
+
+    #[test]
+    fn calculate_times_with_date_out_of_range() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+
+        assert_eq!(
+            PrayerTimes::new(NaiveDate::MAX, coordinates, params),
+            Err(Error::DateOutOfRange)
+        );
+        assert_eq!(
+            PrayerTimes::new(NaiveDate::MIN, coordinates, params),
+            Err(Error::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn calculate_times_for_locations() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let locations = [
+            Coordinates::new(35.7750, -78.6336),
+            Coordinates::new(21.4225, 39.8262),
+            Coordinates::new(95.0, 10.0),
+            Coordinates::new(-33.8688, 151.2093),
+            Coordinates::new(69.6492, 18.9553),
+        ];
+        let results = PrayerTimes::for_locations(date, &locations, params);
+
+        assert_eq!(results.len(), locations.len());
+
+        for (result, coordinates) in results.iter().zip(locations) {
+            assert_eq!(*result, PrayerTimes::new(date, coordinates, params));
+        }
+
+        assert_eq!(results[2], Err(Error::InvalidCoordinates(locations[2])));
+    }
+
+    #[test]
+    fn calculate_times_for_locations_with_date_out_of_range() {
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let locations = [
+            Coordinates::new(35.7750, -78.6336),
+            Coordinates::new(21.4225, 39.8262),
+        ];
+
+        assert_eq!(
+            PrayerTimes::for_locations(NaiveDate::MAX, &locations, params),
+            vec![Err(Error::DateOutOfRange), Err(Error::DateOutOfRange)]
+        );
+        assert_eq!(
+            PrayerTimes::for_locations(NaiveDate::MAX, &[], params),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn calculate_times_during_polar_day_without_resolution() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let params = Configuration::new(18.0, 17.0).done();
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.time(Prayer::Fajr), None);
+        assert_eq!(times.time(Prayer::Sunrise), None);
+        assert_eq!(times.time(Prayer::Maghrib), None);
+        assert_eq!(times.time(Prayer::Isha), None);
+        assert_eq!(times.status(Prayer::Fajr), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Sunrise), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Maghrib), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Isha), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Dhuhr), PrayerStatus::Computed);
+        assert_eq!(times.status(Prayer::Asr), PrayerStatus::Computed);
+        assert_eq!(
+            times.try_time(Prayer::Fajr),
+            Err(Error::SunNeverReachesAngle {
+                prayer: Prayer::Fajr,
+                angle: -18.0
+            })
+        );
+        assert_eq!(
+            times.try_time(Prayer::Maghrib),
+            Err(Error::SunNeverReachesAngle {
+                prayer: Prayer::Maghrib,
+                angle: -50.0 / 60.0
+            })
+        );
+        assert_eq!(
+            times.try_time(Prayer::Dhuhr).ok(),
+            times.time(Prayer::Dhuhr)
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "10:46 AM"
+        );
+    }
+
+    #[test]
+    fn calculate_times_during_polar_night_without_resolution() {
+        let date = NaiveDate::from_ymd_opt(2022, 12, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let params = Configuration::new(18.0, 17.0).done();
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.status(Prayer::Fajr), PrayerStatus::Computed);
+        assert_eq!(times.status(Prayer::Dhuhr), PrayerStatus::Computed);
+        assert_eq!(times.status(Prayer::Isha), PrayerStatus::Computed);
+        assert_eq!(times.status(Prayer::Sunrise), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Asr), PrayerStatus::Undefined);
+        assert_eq!(times.status(Prayer::Maghrib), PrayerStatus::Undefined);
+        assert_eq!(times.time(Prayer::Asr), None);
+        assert_eq!(
+            times.try_time(Prayer::Asr),
+            Err(Error::SunNeverReachesAngle {
+                prayer: Prayer::Asr,
+                angle: -50.0 / 60.0
+            })
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "5:28 AM"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "3:44 PM"
+        );
+    }
+
+    #[test]
+    fn calculate_times_during_polar_day_using_nearest_day() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let params = Configuration::new(18.0, 17.0)
+            .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .polar_resolution(PolarResolution::AqrabYaum)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+
+        for prayer in prayers {
+            assert_eq!(times.status(prayer), PrayerStatus::Derived);
+        }
+
+        for pair in prayers.windows(2) {
+            assert!(times.time(pair[0]).unwrap() < times.time(pair[1]).unwrap());
+        }
+    }
+
+    #[test]
+    fn calculate_times_during_polar_night_using_nearest_latitude() {
+        let date = NaiveDate::from_ymd_opt(2022, 12, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let params = Configuration::new(18.0, 17.0)
+            .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+            .polar_resolution(PolarResolution::AqrabBalad)
+            .done();
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let prayers = [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+        ];
+
+        for prayer in prayers {
+            assert_eq!(times.status(prayer), PrayerStatus::Derived);
+        }
+
+        for pair in prayers.windows(2) {
+            assert!(times.time(pair[0]).unwrap() < times.time(pair[1]).unwrap());
+        }
+
+        assert_eq!(
+            times
+                .time(Prayer::Sunrise)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "9:23 AM"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Maghrib)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "12:01 PM"
+        );
+    }
+
+    #[test]
+    fn high_latitude_rule_times_are_derived() {
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(59.9139, 10.7522);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.status(Prayer::Fajr), PrayerStatus::Derived);
+        assert_eq!(times.status(Prayer::Isha), PrayerStatus::Derived);
+        assert_eq!(times.status(Prayer::Sunrise), PrayerStatus::Computed);
+        assert_eq!(times.status(Prayer::Maghrib), PrayerStatus::Computed);
+    }
+
+    #[test]
+    fn calculate_times_for_russia_at_high_latitude() {
+        // Moscow, where the twilight lasts all night in summer.
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let coordinates = Coordinates::new(55.7558, 37.6173);
+        let times = PrayerTimes::new(date, coordinates, Method::Russia.parameters())
+            .expect("Prayer times could not be calculated");
+        let expected = PrayerTimes::new(
+            date,
+            coordinates,
+            Configuration::new(16.0, 15.0)
+                .high_latitude_rule(HighLatitudeRule::SeventhOfTheNight)
+                .done(),
+        )
+        .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.status(Prayer::Fajr), PrayerStatus::Derived);
+        assert_eq!(times.status(Prayer::Isha), PrayerStatus::Derived);
+        assert_eq!(times.time(Prayer::Fajr), expected.time(Prayer::Fajr));
+        assert_eq!(times.time(Prayer::Isha), expected.time(Prayer::Isha));
+    }
+
+    #[test]
+    fn calculate_isha_interval_during_ramadan() {
+        // Makkah, on the day before the first night of Ramadan 1444, on the
+        // last day of Sha'ban with the first night of Ramadan, on the last
+        // day of Ramadan with the night of Eid, and on the day of Eid.
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+
+        for method in [Method::UmmAlQura, Method::Qatar] {
+            for (month, day, interval) in [(3, 21, 90), (3, 22, 120), (4, 20, 90), (4, 21, 90)] {
+                let date =
+                    NaiveDate::from_ymd_opt(2023, month, day).expect("Invalid date provided");
+                let times = PrayerTimes::new(date, coordinates, method.parameters())
+                    .expect("Prayer times could not be calculated");
+
+                assert_eq!(
+                    times.time(Prayer::Isha).unwrap() - times.time(Prayer::Maghrib).unwrap(),
+                    Duration::minutes(interval),
+                    "Isha of {:?} on {}",
+                    method,
+                    date
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hijri_date_of_prayer_times() {
+        let date = NaiveDate::from_ymd_opt(2023, 3, 23).expect("Invalid date provided");
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let times = PrayerTimes::new(date, coordinates, Method::UmmAlQura.parameters())
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(
+            times.hijri_date(HijriCalendar::UmmAlQura),
+            HijriDate::new(1444, HijriMonth::Ramadan, 1)
+        );
+        assert_eq!(
+            times.hijri_date(HijriCalendar::Tabular),
+            HijriDate::new(1444, HijriMonth::Ramadan, 1)
+        );
+    }
+
+    #[test]
+    fn calculated_times_are_computed() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        for prayer in [
+            Prayer::Fajr,
+            Prayer::Sunrise,
+            Prayer::Dhuhr,
+            Prayer::Asr,
+            Prayer::Maghrib,
+            Prayer::Isha,
+            Prayer::Qiyam,
+            Prayer::FajrTomorrow,
+        ] {
+            assert_eq!(times.status(prayer), PrayerStatus::Computed);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_prayer_times_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let prayer_times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let json = serde_json::to_value(prayer_times).unwrap();
+
+        assert_eq!(json["fajr"]["time"], "2015-07-12T08:42:00Z");
+        assert_eq!(json["fajr"]["status"], "computed");
+        assert_eq!(json["isha"]["time"], "2015-07-13T01:57:00Z");
+        assert_eq!(json["parameters"]["method"], "north_america");
+        assert_eq!(
+            serde_json::from_value::<PrayerTimes>(json).unwrap(),
+            prayer_times
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_prayer_round_trip() {
+        assert_eq!(
+            serde_json::to_string(&Prayer::FajrTomorrow).unwrap(),
+            "\"fajr_tomorrow\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Prayer>("\"maghrib\"").unwrap(),
+            Prayer::Maghrib
+        );
+    }
 }
@@ -0,0 +1,490 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Prayer Timeline
+//!
+//! This module provides the prayer times of a location as one
+//! continuous sequence, across the boundaries of the days.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Days, Duration, NaiveDate, Utc};
+
+use crate::astronomy::unit::Coordinates;
+use crate::error::Error;
+use crate::hijri::{HijriCalendar, HijriDate};
+use crate::models::parameters::Parameters;
+use crate::models::prayer::Prayer;
+use crate::schedule::PrayerTimes;
+
+/// The prayers of a day in the order they occur. `FajrTomorrow` is
+/// left out since it is the `Fajr` of the following day.
+const DAILY_PRAYERS: [Prayer; 7] = [
+    Prayer::Fajr,
+    Prayer::Sunrise,
+    Prayer::Dhuhr,
+    Prayer::Asr,
+    Prayer::Maghrib,
+    Prayer::Isha,
+    Prayer::Qiyam,
+];
+
+// A prayer along with its time.
+type Event = (Prayer, DateTime<Utc>);
+
+/// The prayer times of a location, as a sequence that is
+/// not bound to a single day.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct PrayerTimeline {
+    coordinates: Coordinates,
+    parameters: Parameters,
+}
+
+impl PrayerTimeline {
+    pub fn new(coordinates: Coordinates, parameters: Parameters) -> Result<PrayerTimeline, Error> {
+        if !coordinates.is_valid() {
+            return Err(Error::InvalidCoordinates(coordinates));
+        }
+
+        Ok(PrayerTimeline {
+            coordinates,
+            parameters,
+        })
+    }
+
+    /// Returns the prayers that occur at, or after, the given time,
+    /// in the order they occur. Prayers that have no time on a day
+    /// are skipped, and the sequence only ends when the dates are
+    /// out of range.
+    pub fn iter_from(&self, time: DateTime<Utc>) -> PrayerEvents {
+        // The prayers of a day are spread over more than
+        // one date, so the days before are included too.
+        let date = time.date_naive().checked_sub_days(Days::new(2));
+
+        PrayerEvents {
+            coordinates: self.coordinates,
+            parameters: self.parameters,
+            date,
+            from: time,
+            events: VecDeque::new(),
+            settled: None,
+        }
+    }
+
+    /// Returns the prayer that is current at the given time,
+    /// along with the time it started.
+    pub fn current(&self, time: DateTime<Utc>) -> Result<(Prayer, DateTime<Utc>), Error> {
+        self.preceding(time).map(|(current, _)| current)
+    }
+
+    /// Returns the prayer before the one that is current
+    /// at the given time, along with its time.
+    pub fn previous(&self, time: DateTime<Utc>) -> Result<(Prayer, DateTime<Utc>), Error> {
+        self.preceding(time).map(|(_, previous)| previous)
+    }
+
+    /// Returns the first prayer after the given time,
+    /// along with its time.
+    pub fn next(&self, time: DateTime<Utc>) -> Result<(Prayer, DateTime<Utc>), Error> {
+        self.iter_from(time)
+            .find(|(_, prayer_time)| *prayer_time > time)
+            .ok_or(Error::DateOutOfRange)
+    }
+
+    /// Returns the date whose Hijri date is in effect at the given time.
+    /// The Islamic day starts at Maghrib, so from Maghrib onwards this is
+    /// the date that follows the date of the Maghrib, e.g. the first night
+    /// of Ramadan is the evening before its first fast.
+    ///
+    /// On the days the sun does not set, and Maghrib has no time, the
+    /// date changes at midnight in mean solar time instead.
+    pub fn islamic_date(&self, time: DateTime<Utc>) -> Result<NaiveDate, Error> {
+        // The dates are those of the solar days of the location, so the
+        // last Maghrib can be on the dates around the date in UTC.
+        let mut date = time
+            .date_naive()
+            .checked_add_days(Days::new(1))
+            .ok_or(Error::DateOutOfRange)?;
+
+        for _ in 0..4 {
+            if self.day_end(date)? <= time {
+                return date.succ_opt().ok_or(Error::DateOutOfRange);
+            } else {
+                date = date.pred_opt().ok_or(Error::DateOutOfRange)?;
+            }
+        }
+
+        Err(Error::DateOutOfRange)
+    }
+
+    /// Returns the Hijri date that is in effect at the given time,
+    /// which changes at Maghrib. See [islamic_date](#method.islamic_date).
+    pub fn hijri_date(
+        &self,
+        time: DateTime<Utc>,
+        calendar: HijriCalendar,
+    ) -> Result<HijriDate, Error> {
+        self.islamic_date(time)
+            .map(|date| HijriDate::from_gregorian(date, calendar))
+    }
+
+    // Returns the end of the Islamic day that starts on the evening
+    // before the date, which is the Maghrib of the date.
+    fn day_end(&self, date: NaiveDate) -> Result<DateTime<Utc>, Error> {
+        let times = PrayerTimes::new(date, self.coordinates, self.parameters)?;
+
+        match times.time(Prayer::Maghrib) {
+            Some(maghrib) => Ok(maghrib),
+            None => {
+                let midnight = date
+                    .succ_opt()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .ok_or(Error::DateOutOfRange)?
+                    .and_utc();
+                let offset = Duration::seconds((self.coordinates.longitude * 240.0) as i64);
+
+                Ok(midnight - offset)
+            }
+        }
+    }
+
+    // Returns the last two prayers at, or before, the given time.
+    fn preceding(&self, time: DateTime<Utc>) -> Result<(Event, Event), Error> {
+        let mut current = None;
+        let mut previous = None;
+
+        // Dhuhr is always on the date it is calculated for, so
+        // starting two days back finds at least two prayers.
+        let start = time
+            .date_naive()
+            .checked_sub_days(Days::new(2))
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .ok_or(Error::DateOutOfRange)?
+            .and_utc();
+
+        for event in self.iter_from(start) {
+            if event.1 > time {
+                break;
+            } else {
+                previous = current;
+                current = Some(event);
+            }
+        }
+
+        current.zip(previous).ok_or(Error::DateOutOfRange)
+    }
+}
+
+/// An iterator over the prayers of a [PrayerTimeline](struct.PrayerTimeline.html).
+#[derive(PartialEq, Debug, Clone)]
+pub struct PrayerEvents {
+    coordinates: Coordinates,
+    parameters: Parameters,
+    date: Option<NaiveDate>,
+    from: DateTime<Utc>,
+    events: VecDeque<Event>,
+    settled: Option<DateTime<Utc>>,
+}
+
+impl PrayerEvents {
+    // Adds the prayers of the next date to the events, in time order.
+    fn add_day(&mut self) -> Option<()> {
+        let date = self.date?;
+        let times = PrayerTimes::new(date, self.coordinates, self.parameters).ok()?;
+        let events = DAILY_PRAYERS
+            .iter()
+            .filter_map(|prayer| times.time(*prayer).map(|time| (*prayer, time)))
+            .filter(|(_, time)| *time >= self.from);
+
+        self.events.extend(events);
+        self.events.make_contiguous().sort_by_key(|(_, time)| *time);
+        self.settled = times.time(Prayer::Dhuhr).or(self.settled);
+        self.date = date.succ_opt();
+
+        Some(())
+    }
+
+    // Whether no prayer of the dates that are yet to be added
+    // can come before the first of the events.
+    fn is_settled(&self) -> bool {
+        match (self.events.front(), self.settled) {
+            (Some((_, time)), Some(settled)) => *time <= settled,
+            _ => false,
+        }
+    }
+}
+
+impl Iterator for PrayerEvents {
+    type Item = (Prayer, DateTime<Utc>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Close to the polar circles, Isha of a day can be later than
+        // Fajr of the next day, so the days are merged. The prayers of
+        // the next dates all come after the Dhuhr of the last date.
+        while !self.is_settled() {
+            if self.add_day().is_none() {
+                break;
+            } else {
+                // Nothing to do.
+            }
+        }
+
+        self.events.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hijri::month::HijriMonth;
+    use crate::models::madhab::Madhab;
+    use crate::models::method::Method;
+    use crate::models::parameters::Configuration;
+
+    #[test]
+    fn timeline_is_ordered_across_days() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let start = NaiveDate::from_ymd_opt(2015, 7, 12)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .expect("Invalid date provided")
+            .and_utc();
+        let events: Vec<(Prayer, DateTime<Utc>)> = timeline.iter_from(start).take(30).collect();
+
+        assert_eq!(events.len(), 30);
+        assert!(events.iter().all(|(_, time)| *time >= start));
+        assert!(events.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert!(
+            events
+                .iter()
+                .all(|(prayer, _)| *prayer != Prayer::FajrTomorrow)
+        );
+    }
+
+    #[test]
+    fn timeline_is_ordered_near_the_polar_circle() {
+        // Tromsø, where Isha on 26 July 2024 is later than Fajr
+        // of the next day.
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let date = NaiveDate::from_ymd_opt(2024, 7, 26).expect("Invalid date provided");
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let today = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let tomorrow = PrayerTimes::new(date.succ_opt().unwrap(), coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let isha = today.time(Prayer::Isha).unwrap();
+        let fajr = tomorrow.time(Prayer::Fajr).unwrap();
+        let start = today.time(Prayer::Fajr).unwrap();
+        let events: Vec<(Prayer, DateTime<Utc>)> = timeline.iter_from(start).take(21).collect();
+
+        assert!(fajr < isha);
+        assert_eq!(events.len(), 21);
+        assert!(events.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(events.contains(&(Prayer::Isha, isha)));
+        assert!(events.contains(&(Prayer::Fajr, fajr)));
+    }
+
+    #[test]
+    fn timeline_matches_daily_prayer_times() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let fajr = times.time(Prayer::Fajr).unwrap();
+        let events: Vec<(Prayer, DateTime<Utc>)> = timeline.iter_from(fajr).take(7).collect();
+        let expected: Vec<(Prayer, DateTime<Utc>)> = DAILY_PRAYERS
+            .iter()
+            .map(|prayer| (*prayer, times.time(*prayer).unwrap()))
+            .collect();
+
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    fn current_prayer_before_fajr() {
+        // Given the below DateTime, Fajr is at 2015-07-12T08:42:00Z
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let yesterday = PrayerTimes::new(
+            NaiveDate::from_ymd_opt(2015, 7, 11).expect("Invalid date provided"),
+            coordinates,
+            params,
+        )
+        .expect("Prayer times could not be calculated");
+        let today = PrayerTimes::new(
+            NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided"),
+            coordinates,
+            params,
+        )
+        .expect("Prayer times could not be calculated");
+        let time = NaiveDate::from_ymd_opt(2015, 7, 12)
+            .and_then(|date| date.and_hms_opt(8, 0, 0))
+            .expect("Invalid date provided")
+            .and_utc();
+
+        assert_eq!(
+            timeline.current(time),
+            Ok((Prayer::Qiyam, yesterday.time(Prayer::Qiyam).unwrap()))
+        );
+        assert_eq!(
+            timeline.previous(time),
+            Ok((Prayer::Isha, yesterday.time(Prayer::Isha).unwrap()))
+        );
+        assert_eq!(
+            timeline.next(time),
+            Ok((Prayer::Fajr, today.time(Prayer::Fajr).unwrap()))
+        );
+    }
+
+    #[test]
+    fn current_prayer_at_prayer_time() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let times = PrayerTimes::new(
+            NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided"),
+            coordinates,
+            params,
+        )
+        .expect("Prayer times could not be calculated");
+        let dhuhr = times.time(Prayer::Dhuhr).unwrap();
+
+        assert_eq!(timeline.current(dhuhr), Ok((Prayer::Dhuhr, dhuhr)));
+        assert_eq!(
+            timeline.previous(dhuhr),
+            Ok((Prayer::Sunrise, times.time(Prayer::Sunrise).unwrap()))
+        );
+        assert_eq!(
+            timeline.next(dhuhr),
+            Ok((Prayer::Asr, times.time(Prayer::Asr).unwrap()))
+        );
+    }
+
+    #[test]
+    fn timeline_skips_undefined_prayers() {
+        let params = Configuration::new(18.0, 17.0).done();
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let start = NaiveDate::from_ymd_opt(2022, 6, 21)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .expect("Invalid date provided")
+            .and_utc();
+        let prayers: Vec<Prayer> = timeline
+            .iter_from(start)
+            .take(4)
+            .map(|(prayer, _)| prayer)
+            .collect();
+
+        assert_eq!(
+            prayers,
+            vec![Prayer::Dhuhr, Prayer::Asr, Prayer::Dhuhr, Prayer::Asr]
+        );
+        assert_eq!(
+            timeline.current(start).map(|(prayer, _)| prayer),
+            Ok(Prayer::Asr)
+        );
+    }
+
+    #[test]
+    fn hijri_date_changes_at_maghrib() {
+        let params = Configuration::with(Method::UmmAlQura, Madhab::Shafi);
+        let coordinates = Coordinates::new(21.4225, 39.8262);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let calendar = HijriCalendar::UmmAlQura;
+        let dates = [
+            (
+                NaiveDate::from_ymd_opt(2023, 3, 22).expect("Invalid date provided"),
+                HijriDate::new(1444, HijriMonth::Shaban, 30),
+                HijriDate::new(1444, HijriMonth::Ramadan, 1),
+            ),
+            (
+                NaiveDate::from_ymd_opt(2023, 4, 20).expect("Invalid date provided"),
+                HijriDate::new(1444, HijriMonth::Ramadan, 29),
+                HijriDate::new(1444, HijriMonth::Shawwal, 1),
+            ),
+        ];
+
+        for (date, before, after) in dates {
+            let times = PrayerTimes::new(date, coordinates, params)
+                .expect("Prayer times could not be calculated");
+            let maghrib = times.time(Prayer::Maghrib).unwrap();
+
+            assert_eq!(
+                timeline.hijri_date(maghrib - Duration::minutes(1), calendar),
+                Ok(before)
+            );
+            assert_eq!(timeline.hijri_date(maghrib, calendar), Ok(after));
+            assert_eq!(
+                timeline.hijri_date(maghrib + Duration::hours(8), calendar),
+                Ok(after)
+            );
+        }
+    }
+
+    #[test]
+    fn islamic_date_west_of_greenwich() {
+        // Maghrib in Los Angeles on 2023-03-22 is on 2023-03-23 in UTC.
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Shafi);
+        let coordinates = Coordinates::new(34.0522, -118.2437);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let date = NaiveDate::from_ymd_opt(2023, 3, 22).expect("Invalid date provided");
+        let times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+        let maghrib = times.time(Prayer::Maghrib).unwrap();
+
+        assert_eq!(maghrib.date_naive(), date.succ_opt().unwrap());
+        assert_eq!(
+            timeline.islamic_date(maghrib - Duration::minutes(1)),
+            Ok(date)
+        );
+        assert_eq!(
+            timeline.islamic_date(maghrib),
+            date.succ_opt().ok_or(Error::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn islamic_date_without_maghrib() {
+        // The sun does not set in Tromsø on 2022-06-21, so the date
+        // changes at midnight in mean solar time, about 22:44 in UTC.
+        let params = Configuration::new(18.0, 17.0).done();
+        let coordinates = Coordinates::new(69.6492, 18.9553);
+        let timeline =
+            PrayerTimeline::new(coordinates, params).expect("Timeline could not be created");
+        let date = NaiveDate::from_ymd_opt(2022, 6, 21).expect("Invalid date provided");
+        let time = |hour, minute| date.and_hms_opt(hour, minute, 0).unwrap().and_utc();
+
+        assert_eq!(timeline.islamic_date(time(22, 40)), Ok(date));
+        assert_eq!(
+            timeline.islamic_date(time(22, 50)),
+            date.succ_opt().ok_or(Error::DateOutOfRange)
+        );
+    }
+
+    #[test]
+    fn timeline_with_invalid_coordinates() {
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let coordinates = Coordinates::new(35.7750, -190.0);
+
+        assert_eq!(
+            PrayerTimeline::new(coordinates, params),
+            Err(Error::InvalidCoordinates(coordinates))
+        );
+    }
+}
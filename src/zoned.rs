@@ -0,0 +1,355 @@
+// Salah
+//
+// See LICENSE for more details.
+// Copyright (c) 2019-2022 Farhan Ahmed. All rights reserved.
+//
+
+//! # Zoned Prayer Times
+//!
+//! This module provides the prayer times in the time zone
+//! of the observer, for a date on the observer's calendar.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Offset, TimeZone, Utc};
+
+use crate::astronomy::unit::Coordinates;
+use crate::error::Error;
+use crate::hijri::{HijriCalendar, HijriDate};
+use crate::models::parameters::Parameters;
+use crate::models::prayer::Prayer;
+use crate::models::status::PrayerStatus;
+use crate::schedule::PrayerTimes;
+
+/// The prayer times for a date in the given time zone.
+///
+/// The time zone can be anything that implements `chrono::TimeZone`,
+/// e.g. a `FixedOffset`, or an IANA time zone from `chrono_tz` when
+/// the `chrono-tz` feature is enabled.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct ZonedPrayerTimes<Tz: TimeZone> {
+    times: PrayerTimes,
+    timezone: Tz,
+    date: NaiveDate,
+}
+
+impl<Tz: TimeZone> ZonedPrayerTimes<Tz> {
+    /// Calculates the prayer times for the given date, as it
+    /// is on the calendar of the time zone.
+    pub fn new(
+        date: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+        timezone: Tz,
+    ) -> Result<ZonedPrayerTimes<Tz>, Error> {
+        let solar_date = ZonedPrayerTimes::solar_date(date, coordinates, &timezone)?;
+        let mut times = PrayerTimes::on_local_date(solar_date, date, coordinates, parameters)?;
+
+        // The solar date can be a day off when the offset of the
+        // time zone is about twelve hours from the mean solar time,
+        // so it is corrected for Dhuhr to fall on the requested date.
+        let local_dhuhr = times
+            .time(Prayer::Dhuhr)
+            .map(|time| time.with_timezone(&timezone).date_naive());
+
+        if local_dhuhr.is_some_and(|dhuhr| dhuhr < date) {
+            let next_date = solar_date.succ_opt().ok_or(Error::DateOutOfRange)?;
+
+            times = PrayerTimes::on_local_date(next_date, date, coordinates, parameters)?;
+        } else if local_dhuhr.is_some_and(|dhuhr| dhuhr > date) {
+            let previous_date = solar_date.pred_opt().ok_or(Error::DateOutOfRange)?;
+
+            times = PrayerTimes::on_local_date(previous_date, date, coordinates, parameters)?;
+        } else {
+            // Nothing to do.
+        }
+
+        Ok(ZonedPrayerTimes {
+            times,
+            timezone,
+            date,
+        })
+    }
+
+    /// Returns the time of the prayer in the time zone, or `None`
+    /// when the prayer has no time on this date and location.
+    pub fn time(&self, prayer: Prayer) -> Option<DateTime<Tz>> {
+        self.times
+            .time(prayer)
+            .map(|time| time.with_timezone(&self.timezone))
+    }
+
+    /// Returns the time of the prayer in the time zone, or
+    /// [Error::SunNeverReachesAngle](enum.Error.html) when the prayer
+    /// has no time on this date and location.
+    pub fn try_time(&self, prayer: Prayer) -> Result<DateTime<Tz>, Error> {
+        self.times
+            .try_time(prayer)
+            .map(|time| time.with_timezone(&self.timezone))
+    }
+
+    /// Returns how the time of the prayer was determined.
+    pub fn status(&self, prayer: Prayer) -> PrayerStatus {
+        self.times.status(prayer)
+    }
+
+    pub fn current(&self) -> Result<Prayer, Error> {
+        self.times.current()
+    }
+
+    /// Returns the prayer that is current at the given time.
+    pub fn current_at(&self, time: DateTime<Tz>) -> Result<Prayer, Error> {
+        self.times.current_at(time.with_timezone(&Utc))
+    }
+
+    pub fn next(&self) -> Result<Prayer, Error> {
+        self.times.next()
+    }
+
+    /// Returns the prayer that follows the current prayer at
+    /// the given time.
+    pub fn next_at(&self, time: DateTime<Tz>) -> Result<Prayer, Error> {
+        self.times.next_at(time.with_timezone(&Utc))
+    }
+
+    pub fn time_remaining(&self) -> Result<Duration, Error> {
+        self.times.time_remaining()
+    }
+
+    /// Returns the time remaining from the given time until
+    /// the next prayer, to the second.
+    pub fn time_remaining_at(&self, time: DateTime<Tz>) -> Result<Duration, Error> {
+        self.times.time_remaining_at(time.with_timezone(&Utc))
+    }
+
+    /// Returns the Hijri date of the date on the calendar of the time
+    /// zone, i.e. the date of the daytime prayers. See
+    /// [HijriDate](struct.HijriDate.html) for an offset of days.
+    pub fn hijri_date(&self, calendar: HijriCalendar) -> HijriDate {
+        HijriDate::from_gregorian(self.date, calendar)
+    }
+
+    /// The time zone of the prayer times.
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+
+    /// The same prayer times, in UTC.
+    pub fn utc(&self) -> &PrayerTimes {
+        &self.times
+    }
+
+    // The prayer times are calculated for the day whose solar
+    // transit is nearest to noon on the local calendar. This is
+    // not always the same date, e.g. when the offset of the time
+    // zone is far from the longitude of the observer.
+    fn solar_date(
+        date: NaiveDate,
+        coordinates: Coordinates,
+        timezone: &Tz,
+    ) -> Result<NaiveDate, Error> {
+        let noon = date.and_hms_opt(12, 0, 0).ok_or(Error::DateOutOfRange)?;
+
+        // Noon can be skipped over by a change of offset, in
+        // which case the offset in effect at that time is used.
+        let offset = timezone
+            .offset_from_local_datetime(&noon)
+            .earliest()
+            .unwrap_or_else(|| timezone.offset_from_utc_datetime(&noon))
+            .fix();
+        let mean_solar_offset = Duration::seconds((coordinates.longitude * 240.0).round() as i64);
+
+        noon.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))
+            .and_then(|time| time.checked_add_signed(mean_solar_offset))
+            .map(|time| time.date())
+            .ok_or(Error::DateOutOfRange)
+    }
+}
+
+impl ZonedPrayerTimes<FixedOffset> {
+    /// Calculates the prayer times for the given date, as it is on the
+    /// calendar at the longitude of the observer. The times are in the
+    /// nautical time zone of the longitude, i.e. one hour for every 15°
+    /// east or west of Greenwich.
+    ///
+    /// This should only be used when the actual time zone is not known,
+    /// since the offset of a time zone can be quite far from its nautical
+    /// offset, e.g. for the islands close to the date line.
+    pub fn at_longitude(
+        date: NaiveDate,
+        coordinates: Coordinates,
+        parameters: Parameters,
+    ) -> Result<ZonedPrayerTimes<FixedOffset>, Error> {
+        if !coordinates.is_valid() {
+            return Err(Error::InvalidCoordinates(coordinates));
+        }
+
+        let hours = (coordinates.longitude / 15.0).round() as i32;
+        let timezone =
+            FixedOffset::east_opt(hours * 3600).ok_or(Error::InvalidCoordinates(coordinates))?;
+
+        ZonedPrayerTimes::new(date, coordinates, parameters, timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hijri::month::HijriMonth;
+    use crate::models::madhab::Madhab;
+    use crate::models::method::Method;
+    use crate::models::parameters::Configuration;
+
+    #[test]
+    fn calculate_times_in_fixed_offset() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let timezone = FixedOffset::west_opt(4 * 3600).expect("Invalid offset provided");
+        let times = ZonedPrayerTimes::new(date, coordinates, params, timezone)
+            .expect("Prayer times could not be calculated");
+        let utc_times = PrayerTimes::new(date, coordinates, params)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.utc(), &utc_times);
+        assert_eq!(
+            times
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "4:42 AM"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Maghrib)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "8:32 PM"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "9:57 PM"
+        );
+    }
+
+    #[test]
+    fn isha_interval_on_the_local_calendar() {
+        // Kiritimati, where the prayer times of the local date are those
+        // of the solar date before it. The night of the local date decides
+        // the interval, i.e. the first night of Ramadan 1444 and Eid eve.
+        let coordinates = Coordinates::new(1.8721, -157.4278);
+        let timezone = FixedOffset::east_opt(14 * 3600).expect("Invalid offset provided");
+        let params = Method::UmmAlQura.parameters();
+
+        for (month, day, interval) in [(3, 22, 120), (4, 19, 120), (4, 20, 90)] {
+            let date = NaiveDate::from_ymd_opt(2023, month, day).expect("Invalid date provided");
+            let times = ZonedPrayerTimes::new(date, coordinates, params, timezone)
+                .expect("Prayer times could not be calculated");
+
+            assert_eq!(times.time(Prayer::Maghrib).unwrap().date_naive(), date);
+            assert_eq!(
+                times.time(Prayer::Isha).unwrap() - times.time(Prayer::Maghrib).unwrap(),
+                Duration::minutes(interval),
+                "Isha on {}",
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn calculate_times_on_the_date_line() {
+        // The sun transits before midnight UTC at this time of
+        // year, which moves Dhuhr to the previous solar date.
+        let date = NaiveDate::from_ymd_opt(2022, 11, 3).expect("Invalid date provided");
+        let coordinates = Coordinates::new(-16.0, 179.99);
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let timezone = FixedOffset::west_opt(12 * 3600).expect("Invalid offset provided");
+        let times = ZonedPrayerTimes::new(date, coordinates, params, timezone)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(times.time(Prayer::Dhuhr).unwrap().date_naive(), date);
+        assert_eq!(
+            times
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p")
+                .to_string(),
+            "11:45 AM"
+        );
+    }
+
+    #[test]
+    fn current_and_next_prayer_at_local_time() {
+        let date = NaiveDate::from_ymd_opt(2015, 7, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(35.7750, -78.6336);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Hanafi);
+        let timezone = FixedOffset::west_opt(4 * 3600).expect("Invalid offset provided");
+        let times = ZonedPrayerTimes::new(date, coordinates, params, timezone)
+            .expect("Prayer times could not be calculated");
+        let time = timezone
+            .with_ymd_and_hms(2015, 7, 12, 21, 0, 0)
+            .single()
+            .expect("Invalid time provided");
+
+        assert_eq!(times.current_at(time), Ok(Prayer::Maghrib));
+        assert_eq!(times.next_at(time), Ok(Prayer::Isha));
+        assert_eq!(times.time_remaining_at(time), Ok(Duration::minutes(57)));
+    }
+
+    #[test]
+    fn hijri_date_on_the_local_calendar() {
+        // Auckland, where the prayer times of the local date are
+        // calculated for the day before in UTC.
+        let date = NaiveDate::from_ymd_opt(2023, 3, 23).expect("Invalid date provided");
+        let coordinates = Coordinates::new(-36.8485, 174.7633);
+        let timezone = FixedOffset::east_opt(13 * 3600).expect("Invalid offset provided");
+        let params = Configuration::with(Method::MuslimWorldLeague, Madhab::Shafi);
+        let times = ZonedPrayerTimes::new(date, coordinates, params, timezone)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(
+            times.hijri_date(HijriCalendar::UmmAlQura),
+            HijriDate::new(1444, HijriMonth::Ramadan, 1)
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn calculate_times_on_daylight_saving_transition() {
+        // Clocks in New York moved forward at 2 AM on this date.
+        let date = NaiveDate::from_ymd_opt(2023, 3, 12).expect("Invalid date provided");
+        let coordinates = Coordinates::new(40.7128, -74.0059);
+        let params = Configuration::with(Method::NorthAmerica, Madhab::Shafi);
+        let times = ZonedPrayerTimes::new(date, coordinates, params, chrono_tz::America::New_York)
+            .expect("Prayer times could not be calculated");
+
+        assert_eq!(
+            times
+                .time(Prayer::Fajr)
+                .unwrap()
+                .format("%-l:%M %p %Z")
+                .to_string(),
+            "5:58 AM EDT"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Dhuhr)
+                .unwrap()
+                .format("%-l:%M %p %Z")
+                .to_string(),
+            "1:07 PM EDT"
+        );
+        assert_eq!(
+            times
+                .time(Prayer::Isha)
+                .unwrap()
+                .format("%-l:%M %p %Z")
+                .to_string(),
+            "8:14 PM EDT"
+        );
+    }
+}